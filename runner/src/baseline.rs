@@ -0,0 +1,375 @@
+//! Timing baselines saved per git revision, and comparison against them
+//!
+//! A baseline holds the median and minimum duration of the parse phase, each
+//! part and the total of every timed day, keyed by `YYYY/dayXX/<phase>`, e.g.
+//! `2019/day07/part1`. Baselines are machine-specific, and stored by default
+//! as `baselines/<revision>.json` at the workspace root.
+
+use crate::timing::{format_duration, DayTiming, Stats, TimingOutcome};
+use crate::workspace_root;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BaselineError {
+    #[error("cannot read baseline `{path}`: {source}")]
+    CannotRead { path: PathBuf, source: io::Error },
+    #[error("invalid baseline `{path}`: {source}")]
+    Invalid {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("cannot write baseline `{path}`: {source}")]
+    CannotWrite { path: PathBuf, source: io::Error },
+}
+
+/// Default location of the baseline of a revision
+pub fn baseline_path(revision: &str) -> PathBuf {
+    workspace_root()
+        .join("baselines")
+        .join(format!("{}.json", revision))
+}
+
+/// Trimmed output of a successful git command run in `root`
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Current git revision, suffixed with `-dirty` when the tree has changes
+pub fn git_revision(root: &Path) -> Option<String> {
+    let revision = git(root, &["rev-parse", "--short=12", "HEAD"])?;
+    match git(root, &["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if changes.is_empty() => Some(revision),
+        _ => Some(format!("{}-dirty", revision)),
+    }
+}
+
+/// Revision as named by baselines, e.g. `main` or a full SHA shortened to 12
+/// characters, `None` when git does not know it
+pub fn resolve_revision(root: &Path, revision: &str) -> Option<String> {
+    let commit = format!("{}^{{commit}}", revision);
+    git(
+        root,
+        &["rev-parse", "--verify", "--quiet", "--short=12", &commit],
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measure {
+    pub median_ns: u64,
+    pub min_ns: u64,
+}
+
+impl Measure {
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+impl From<&Stats> for Measure {
+    fn from(stats: &Stats) -> Self {
+        Measure {
+            median_ns: stats.median.as_nanos() as u64,
+            min_ns: stats.min.as_nanos() as u64,
+        }
+    }
+}
+
+fn key(year: u16, day: u8, phase: &str) -> String {
+    format!("{}/day{:02}/{}", year, day, phase)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub revision: String,
+    /// Number of runs the medians and minimums are taken from
+    pub repeat: usize,
+    pub timings: BTreeMap<String, Measure>,
+}
+
+impl Baseline {
+    /// Baseline of the timed days, those missing their input or failing being left out
+    pub fn new(revision: &str, repeat: usize, timings: &[DayTiming]) -> Baseline {
+        let mut measures = BTreeMap::new();
+        for timing in timings {
+            let TimingOutcome::Timed { parse, parts } = &timing.outcome else {
+                continue;
+            };
+            measures.insert(key(timing.year, timing.day, "parse"), parse.into());
+            for (part, stats) in parts {
+                let phase = format!("part{}", part);
+                measures.insert(key(timing.year, timing.day, &phase), stats.into());
+            }
+            let total = timing.total().unwrap_or_default().as_nanos() as u64;
+            let min = parse.min + parts.iter().map(|(_, stats)| stats.min).sum::<Duration>();
+            measures.insert(
+                key(timing.year, timing.day, "total"),
+                Measure {
+                    median_ns: total,
+                    min_ns: min.as_nanos() as u64,
+                },
+            );
+        }
+
+        Baseline {
+            revision: revision.to_string(),
+            repeat,
+            timings: measures,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, BaselineError> {
+        let content = fs::read_to_string(path).map_err(|source| BaselineError::CannotRead {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&content).map_err(|source| BaselineError::Invalid {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let cannot_write = |source| BaselineError::CannotWrite {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(cannot_write)?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("a baseline serializes to JSON");
+        fs::write(path, json + "\n").map_err(cannot_write)
+    }
+
+    /// Baseline restricted to the given days
+    pub fn only(&self, days: &[(u16, u8)]) -> Baseline {
+        let prefixes: Vec<String> = days.iter().map(|&(year, day)| key(year, day, "")).collect();
+        Baseline {
+            timings: self
+                .timings
+                .iter()
+                .filter(|(key, _)| prefixes.iter().any(|prefix| key.starts_with(prefix)))
+                .map(|(key, measure)| (key.clone(), *measure))
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Median of a puzzle phase in the baseline and in the current run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub key: String,
+    pub baseline: Option<Duration>,
+    pub current: Option<Duration>,
+}
+
+impl Comparison {
+    /// Relative change, in percent, when measured in both runs
+    pub fn change(&self) -> Option<f64> {
+        match (self.baseline, self.current) {
+            (Some(baseline), Some(current)) if !baseline.is_zero() => Some(
+                (current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64() * 100.0,
+            ),
+            _ => None,
+        }
+    }
+
+    /// Whether this is the total of a day slower than the baseline by more
+    /// than `threshold` percent, or no longer timed because it failed or is
+    /// missing its input
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.key.ends_with("/total")
+            && match (self.baseline, self.current) {
+                (Some(_), None) => true,
+                _ => self.change().is_some_and(|change| change > threshold),
+            }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let duration = |d: Option<Duration>| d.map_or("-".to_string(), format_duration);
+        write!(
+            f,
+            "{:<20}{:>10} -> {:<10}",
+            self.key,
+            duration(self.baseline),
+            duration(self.current)
+        )?;
+        match self.change() {
+            Some(change) => write!(f, "{:+.1}%", change),
+            None if self.baseline.is_none() => write!(f, "new"),
+            None => write!(f, "not run"),
+        }
+    }
+}
+
+/// Comparison of every phase timed in either run, in key order
+pub fn compare(baseline: &Baseline, current: &Baseline) -> Vec<Comparison> {
+    let mut keys: Vec<&String> = baseline
+        .timings
+        .keys()
+        .chain(current.timings.keys())
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .map(|key| Comparison {
+            key: key.clone(),
+            baseline: baseline.timings.get(key).map(Measure::median),
+            current: current.timings.get(key).map(Measure::median),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::Part;
+    use std::env;
+
+    fn stats(micros: u64) -> Stats {
+        Stats {
+            median: Duration::from_micros(micros),
+            min: Duration::from_micros(micros),
+            allocations: None,
+        }
+    }
+
+    fn timings(part1: u64) -> Vec<DayTiming> {
+        vec![
+            DayTiming {
+                year: 2019,
+                day: 7,
+                outcome: TimingOutcome::Timed {
+                    parse: stats(100),
+                    parts: vec![(Part::One, stats(part1)), (Part::Two, stats(400))],
+                },
+            },
+            DayTiming {
+                year: 2019,
+                day: 8,
+                outcome: TimingOutcome::MissingInput(PathBuf::from("day08.txt")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        let baseline = Baseline::new("abc123", 5, &timings(500));
+
+        assert_eq!(
+            vec![
+                "2019/day07/parse",
+                "2019/day07/part1",
+                "2019/day07/part2",
+                "2019/day07/total"
+            ],
+            baseline.timings.keys().collect::<Vec<&String>>()
+        );
+        assert_eq!(
+            Duration::from_micros(1000),
+            baseline.timings["2019/day07/total"].median()
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = env::temp_dir()
+            .join(format!("aoc_baseline_{}", std::process::id()))
+            .join("abc123.json");
+        let baseline = Baseline::new("abc123", 5, &timings(500));
+
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(baseline, loaded.unwrap());
+        assert!(matches!(
+            Baseline::load(Path::new("does/not/exist.json")),
+            Err(BaselineError::CannotRead { .. })
+        ));
+    }
+
+    #[test]
+    fn test_only() {
+        let baseline = Baseline::new("abc123", 5, &timings(500));
+
+        assert_eq!(4, baseline.only(&[(2019, 7)]).timings.len());
+        assert!(baseline.only(&[(2019, 8), (2020, 7)]).timings.is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::new("before", 5, &timings(500));
+        let current = Baseline::new("after", 5, &timings(700));
+
+        let comparisons = compare(&baseline, &current);
+        let total = comparisons
+            .iter()
+            .find(|c| c.key == "2019/day07/total")
+            .unwrap();
+
+        assert_eq!(Some(20.0), total.change().map(|c| c.round()));
+        assert!(total.is_regression(10.0));
+        assert!(!total.is_regression(25.0));
+        // only day totals count as regressions
+        let part1 = comparisons
+            .iter()
+            .find(|c| c.key == "2019/day07/part1")
+            .unwrap();
+        assert!(!part1.is_regression(10.0));
+        assert_eq!(
+            "2019/day07/part1         500µs -> 700µs     +40.0%",
+            part1.to_string()
+        );
+    }
+
+    #[test]
+    fn test_compare_not_run() {
+        let baseline = Baseline::new("before", 5, &timings(500));
+        let mut failed = timings(500);
+        failed[0].outcome = TimingOutcome::Failed("cannot parse input".to_string());
+        let current = Baseline::new("after", 5, &failed);
+
+        let comparisons = compare(&baseline, &current);
+
+        let regressions: Vec<&str> = comparisons
+            .iter()
+            .filter(|c| c.is_regression(10.0))
+            .map(|c| c.key.as_str())
+            .collect();
+        assert_eq!(vec!["2019/day07/total"], regressions);
+        // new days are not regressions
+        assert!(compare(&current, &baseline)
+            .iter()
+            .all(|c| !c.is_regression(10.0)));
+    }
+
+    #[test]
+    fn test_resolve_revision() {
+        let root = workspace_root();
+        let head = resolve_revision(&root, "HEAD").unwrap();
+
+        assert_eq!(12, head.len());
+        assert!(git_revision(&root).unwrap().starts_with(&head));
+        assert_eq!(None, resolve_revision(&root, "does-not-exist"));
+    }
+}
@@ -0,0 +1,209 @@
+//! Authenticated and throttled HTTP client for the Advent of Code website
+
+use crate::workspace_root;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Minimum delay between two requests of a client
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = "github.com/benjaminch/advent-of-code runner";
+
+/// Environment variable holding the session token
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the session file path
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+/// Environment variable overriding the website base URL
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("no session token: set `{SESSION_VAR}` or write it to `{}`", .0.display())]
+    MissingSession(PathBuf),
+    #[error("cannot read session file `{path}`: {source}")]
+    CannotReadSession { path: PathBuf, source: io::Error },
+    #[error("`{url}` answered {status}: {message}")]
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+    #[error("request to `{url}` failed: {message}")]
+    Transport { url: String, message: String },
+}
+
+/// Session file used when `AOC_SESSION_FILE` is not set
+pub fn default_session_file() -> PathBuf {
+    workspace_root().join(".aoc_session")
+}
+
+/// Session token from `AOC_SESSION`, or else from the session file
+pub fn session_from_env() -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = env::var_os(SESSION_FILE_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(default_session_file);
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(ClientError::MissingSession(path)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ClientError::MissingSession(path)),
+        Err(source) => Err(ClientError::CannotReadSession { path, source }),
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    throttle: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            throttle: DEFAULT_THROTTLE,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Client configured from `AOC_BASE_URL` and the session token
+    pub fn from_env() -> Result<Client, ClientError> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session_from_env()?))
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Client {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Wait until the throttle delay since the previous request has elapsed
+    fn wait_turn(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    /// Authenticated request, sent once the throttle allows it
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.wait_turn();
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self.request("GET", &url).call();
+        read_response(url, response)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = self.url(path);
+        let response = self.request("POST", &url).send_form(form);
+        read_response(url, response)
+    }
+}
+
+fn read_response(
+    url: String,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(|e| ClientError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(ClientError::Status {
+                url,
+                status,
+                message: body.lines().next().unwrap_or("").trim().to_string(),
+            })
+        }
+        Err(e) => Err(ClientError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+
+    #[test]
+    fn test_get_sends_session() {
+        let server = FakeServer::start(vec![(200, "1\n2\n".to_string())]);
+        let client = Client::new(&server.url(), "secret").with_throttle(Duration::ZERO);
+
+        assert_eq!("1\n2\n", client.get("/2024/day/1/input").unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn test_status_error() {
+        let server = FakeServer::start(vec![(404, "Not found\nmore".to_string())]);
+        let client = Client::new(&server.url(), "secret");
+
+        match client.get("/2024/day/26/input") {
+            Err(ClientError::Status {
+                status, message, ..
+            }) => {
+                assert_eq!(404, status);
+                assert_eq!("Not found", message);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_throttle() {
+        let server = FakeServer::start(vec![(200, "a".to_string()), (200, "b".to_string())]);
+        let client = Client::new(&server.url(), "secret").with_throttle(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.get("/a").unwrap();
+        client.get("/b").unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+}
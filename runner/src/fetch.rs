@@ -0,0 +1,159 @@
+//! Download of puzzle inputs into the `inputs/` directory of each year
+
+use crate::client::{Client, ClientError};
+use crate::run::default_input_path;
+use aoc_utils::crypt::input_exists;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("cannot write `{path}`: {source}")]
+    CannotWrite { path: PathBuf, source: io::Error },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, nothing was requested
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl fmt::Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fetched::Cached(path) => write!(f, "cached `{}`", path.display()),
+            Fetched::Downloaded(path) => write!(f, "downloaded `{}`", path.display()),
+        }
+    }
+}
+
+/// Download the input of a day into its default location, unless already cached
+///
+/// No client is needed for cached inputs: `client` is only built from the
+/// environment, see [`Client::from_env`], for the first download.
+pub fn fetch_input(client: &mut Option<Client>, year: u16, day: u8) -> Result<Fetched, FetchError> {
+    fetch_input_to(client, year, day, &default_input_path(year, day))
+}
+
+pub fn fetch_input_to(
+    client: &mut Option<Client>,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, FetchError> {
+    // an encrypted input is as good as its plaintext
+    if input_exists(path) {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    if client.is_none() {
+        *client = Some(Client::from_env()?);
+    }
+    let client = client.as_ref().expect("client built above");
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+    write_atomically(path, &input).map_err(|source| FetchError::CannotWrite {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+/// Write through a temporary file so an interrupted download is never taken as cached
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, content)?;
+    fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+    use aoc_utils::crypt::encrypted_path;
+    use std::env;
+    use std::time::Duration;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("inputs").join("day01.txt")
+    }
+
+    fn remove_temp(path: &Path) {
+        let _ = fs::remove_dir_all(path.parent().and_then(Path::parent).unwrap());
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let server = FakeServer::start(vec![(200, "3   4\n4   3\n".to_string())]);
+        let client = &mut Some(Client::new(&server.url(), "secret").with_throttle(Duration::ZERO));
+        let path = temp_path("once");
+
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch_input_to(client, 2024, 1, &path).unwrap()
+        );
+        assert_eq!("3   4\n4   3\n", fs::read_to_string(&path).unwrap());
+
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch_input_to(client, 2024, 1, &path).unwrap()
+        );
+        assert_eq!(1, server.requests().len());
+        assert!(server.requests()[0].starts_with("GET /2024/day/1/input "));
+        remove_temp(&path);
+    }
+
+    #[test]
+    fn test_fetch_input_error_writes_nothing() {
+        let server = FakeServer::start(vec![(400, "Please log in".to_string())]);
+        let client = &mut Some(Client::new(&server.url(), "expired"));
+        let path = temp_path("error");
+
+        assert!(matches!(
+            fetch_input_to(client, 2024, 1, &path),
+            Err(FetchError::Client(ClientError::Status { status: 400, .. }))
+        ));
+        assert!(!path.exists());
+        remove_temp(&path);
+    }
+
+    #[test]
+    fn test_fetch_cached_input_needs_no_client() {
+        let path = temp_path("cached");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "1\n").unwrap();
+        let mut client = None;
+
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch_input_to(&mut client, 2024, 1, &path).unwrap()
+        );
+        assert!(client.is_none());
+        remove_temp(&path);
+    }
+
+    #[test]
+    fn test_fetch_encrypted_input_is_cached() {
+        let path = temp_path("encrypted");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(encrypted_path(&path), "not read\n").unwrap();
+        let mut client = None;
+
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch_input_to(&mut client, 2024, 1, &path).unwrap()
+        );
+        assert!(client.is_none() && !path.exists());
+        remove_temp(&path);
+    }
+}
@@ -0,0 +1,44 @@
+//! Single entry point driving every registered Advent of Code solution
+
+use aoc_utils::Registry;
+use std::path::PathBuf;
+
+pub mod baseline;
+pub mod client;
+pub mod fetch;
+pub mod puzzle;
+pub mod report;
+pub mod run;
+pub mod scale;
+pub mod scaffold;
+pub mod submit;
+pub mod timing;
+pub mod verify;
+pub mod watch;
+
+#[cfg(test)]
+mod fake_server;
+
+/// Registry holding the solutions of every year crate
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2018::register(&mut registry);
+    aoc_2019::register(&mut registry);
+    aoc_2020::register(&mut registry);
+    aoc_2021::register(&mut registry);
+    aoc_2024::register(&mut registry);
+    registry
+}
+
+/// Root directory of the workspace
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate is not in a workspace")
+        .to_path_buf()
+}
+
+/// Directory of a year crate
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_root().join("years").join(year.to_string())
+}
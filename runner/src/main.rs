@@ -0,0 +1,580 @@
+use aoc::baseline::{baseline_path, compare, git_revision, resolve_revision, Baseline};
+use aoc::client::Client;
+use aoc::fetch::fetch_input;
+use aoc::puzzle::Puzzle;
+use aoc::report::{write_report, Format};
+use aoc::run::{parse_param, run, run_parallel, DayOutcome, InputSource, RunSummary, Target};
+use aoc::scaffold::Scaffold;
+use aoc::scale::scale;
+use aoc::submit::{submissions_path, submit, Outcome};
+use aoc::timing::{parse_duration, time, TimingOutcome, YearTable};
+use aoc::verify::{verify, Summary};
+use aoc::watch::Watch;
+use aoc::{registry, workspace_root};
+use aoc_utils::crypt::{encrypt_input, encrypted_path, key_file, CryptError, Key};
+use aoc_utils::{input_path, input_variants, variant_input_path, Answer, Params, Part};
+use clap::{Args, Parser, Subcommand};
+use std::error::Error as StdError;
+use std::fs;
+use std::io::{self, Write};
+use std::iter;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print their answers
+    Run(RunArgs),
+    /// Time the parse phase and each part of solutions, over repeated runs
+    Time(TimeArgs),
+    /// Time solutions and compare them with a saved baseline
+    Compare(CompareArgs),
+    /// Check solutions against the accepted answers of `answers.toml`
+    Verify(VerifyArgs),
+    /// Download missing puzzle inputs into the year's `inputs/` directory
+    Fetch(FetchArgs),
+    /// Submit an answer, computed from the day's input when not given
+    Submit(SubmitArgs),
+    /// Save the puzzle description as Markdown and extract its examples
+    Puzzle(PuzzleArgs),
+    /// Create a year crate, or add a day to it, without overwriting anything
+    New(NewArgs),
+    /// Rebuild and re-run a day on its examples and input whenever they or its sources change
+    Watch(WatchArgs),
+    /// Encrypt inputs into `.enc` files, which can be committed
+    Encrypt(EncryptArgs),
+    /// Print a random valid input of a day, of the given size
+    Generate(GenerateArgs),
+    /// Time a day on generated inputs of growing sizes, showing how it scales
+    Scale(ScaleArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year to run, or `all`
+    year: String,
+    /// Day to run, the whole year when omitted
+    day: Option<u8>,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, `-` for stdin [default: the year's inputs/dayXX.txt]
+    #[arg(long)]
+    input: Option<InputSource>,
+    /// Use the alternative inputs `dayXX.<VARIANT>.txt`, e.g. another account's
+    #[arg(long, conflicts_with = "input")]
+    variant: Option<String>,
+    /// Puzzle parameter `NAME=VALUE`, as set in the header of an example
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Output format: `text`, `json` (one object per part) or `junit` (XML)
+    #[arg(long, default_value = "text")]
+    format: Format,
+    /// Number of days run concurrently [default: the number of CPUs]
+    #[arg(short, long)]
+    jobs: Option<usize>,
+}
+
+#[derive(Args)]
+struct TimeArgs {
+    /// Year to time, or `all`
+    year: String,
+    /// Day to time, the whole year when omitted
+    day: Option<u8>,
+    /// Only time this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Number of runs to take the median and minimum of
+    #[arg(long, default_value_t = 5)]
+    repeat: usize,
+    /// Flag the days taking longer than this, e.g. `500ms` or `1s`
+    #[arg(long, value_parser = parse_duration)]
+    budget: Option<Duration>,
+    /// Use the alternative inputs `dayXX.<VARIANT>.txt`
+    #[arg(long)]
+    variant: Option<String>,
+    /// Save the timings as the baseline of the current git revision
+    #[arg(long)]
+    save_baseline: bool,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// Git revision of the baseline, or path to its JSON file
+    baseline: String,
+    /// Year to time, or `all`
+    #[arg(default_value = "all")]
+    year: String,
+    /// Day to time, the whole year when omitted
+    day: Option<u8>,
+    /// Fail when the total of a day is slower by more than this percentage
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Number of runs to take the median and minimum of
+    #[arg(long, default_value_t = 5)]
+    repeat: usize,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Year to verify, or `all`
+    #[arg(default_value = "all")]
+    year: String,
+    /// Day to verify, the whole year when omitted
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Year to fetch, or `all`
+    year: String,
+    /// Day to fetch, every registered day of the year when omitted
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u16,
+    day: u8,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit [default: the solution's answer on the day's input]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct PuzzleArgs {
+    year: u16,
+    day: u8,
+    /// Saved puzzle page to convert instead of downloading it
+    #[arg(long)]
+    html: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+    /// Day to add, only the year crate when omitted
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    year: u16,
+    day: u8,
+    /// Build and run in release mode
+    #[arg(long)]
+    release: bool,
+}
+
+#[derive(Args)]
+struct EncryptArgs {
+    /// Year to encrypt, or `all`
+    year: String,
+    /// Day to encrypt, every registered day of the year when omitted
+    day: Option<u8>,
+    /// Generate the key first, never replacing an existing one
+    #[arg(long)]
+    generate_key: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    year: u16,
+    day: u8,
+    /// Size of the input, in the unit of the day's generator
+    #[arg(long)]
+    size: usize,
+    /// Seed of the input, the same seed always giving the same input
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct ScaleArgs {
+    year: u16,
+    day: u8,
+    /// Sizes of the generated inputs, in the unit of the day's generator
+    #[arg(long, value_delimiter = ',', default_values_t = [10, 100, 1000])]
+    sizes: Vec<usize>,
+    /// Seed of the generated inputs
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+    /// Only time this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Number of runs to take the median of
+    #[arg(long, default_value_t = 3)]
+    repeat: usize,
+}
+
+fn parts(part: Option<u8>) -> Result<Vec<Part>, String> {
+    match part {
+        Some(part) => Ok(vec![Part::try_from(part)?]),
+        None => Ok(Part::ALL.to_vec()),
+    }
+}
+
+fn run_command(args: RunArgs) -> Result<bool, Box<dyn StdError>> {
+    let target = Target::new(&args.year, args.day)?;
+    if args.input.is_some() && !matches!(target, Target::Day(..)) {
+        return Err("--input can only be used when running a single day".into());
+    }
+
+    let registry = registry();
+    let solutions = target.select(&registry)?;
+    let source = match (args.input, args.variant) {
+        (Some(input), _) => input,
+        (None, Some(variant)) => InputSource::Variant(variant),
+        (None, None) => InputSource::Default,
+    };
+
+    let mut params = Params::new();
+    for (name, value) in &args.params {
+        params.insert(name, value);
+    }
+
+    let parts = parts(args.part)?;
+    let jobs = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let day_runs = run_parallel(&solutions, &source, &params, &parts, jobs);
+    write_report(&mut io::stdout(), args.format, &day_runs, &parts)?;
+
+    // keep stdout parseable for the machine-readable formats
+    let summary = RunSummary {
+        day_runs: &day_runs,
+    };
+    match args.format {
+        _ if day_runs.len() == 1 => {}
+        Format::Text => writeln!(io::stdout(), "summary: {}", summary)?,
+        _ => eprintln!("summary: {}", summary),
+    }
+    Ok(!day_runs.iter().any(|day_run| day_run.has_failed()))
+}
+
+fn time_command(args: TimeArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::new(&args.year, args.day)?.select(&registry)?;
+    let source = match args.variant {
+        Some(variant) => InputSource::Variant(variant),
+        None => InputSource::Default,
+    };
+
+    let parts = parts(args.part)?;
+    let timings = time(&solutions, &source, &parts, args.repeat);
+    let mut years: Vec<u16> = timings.iter().map(|t| t.year).collect();
+    years.dedup();
+    for (i, year) in years.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(io::stdout())?;
+        }
+        let table = YearTable {
+            year,
+            timings: &timings,
+            parts: &parts,
+            budget: args.budget,
+        };
+        write!(io::stdout(), "{}", table)?;
+    }
+
+    if args.save_baseline {
+        let revision = git_revision(&workspace_root()).ok_or("cannot find the git revision")?;
+        let path = baseline_path(&revision);
+        Baseline::new(&revision, args.repeat, &timings).save(&path)?;
+        writeln!(io::stdout(), "saved baseline `{}`", path.display())?;
+    }
+
+    let failed = timings
+        .iter()
+        .any(|t| matches!(t.outcome, TimingOutcome::Failed(_)));
+    let over_budget = args
+        .budget
+        .is_some_and(|budget| timings.iter().any(|t| t.exceeds(budget)));
+    Ok(!failed && !over_budget)
+}
+
+fn compare_command(args: CompareArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::new(&args.year, args.day)?.select(&registry)?;
+    let root = workspace_root();
+    let path = match PathBuf::from(&args.baseline) {
+        path if path.extension().is_some_and(|ext| ext == "json") => path,
+        // branch names and full SHAs name the same baseline as `--save-baseline`
+        _ => baseline_path(
+            &resolve_revision(&root, &args.baseline).unwrap_or_else(|| args.baseline.clone()),
+        ),
+    };
+    let days: Vec<(u16, u8)> = solutions.iter().map(|s| (s.year(), s.day())).collect();
+    let baseline = Baseline::load(&path)?.only(&days);
+
+    let timings = time(&solutions, &InputSource::Default, &Part::ALL, args.repeat);
+    let revision = git_revision(&root).unwrap_or_else(|| "current".to_string());
+    let current = Baseline::new(&revision, args.repeat, &timings);
+
+    writeln!(
+        io::stdout(),
+        "{} -> {}",
+        baseline.revision,
+        current.revision
+    )?;
+    let mut regressions = 0;
+    for comparison in compare(&baseline, &current) {
+        if comparison.is_regression(args.threshold) {
+            regressions += 1;
+            writeln!(io::stdout(), "{}  REGRESSION", comparison)?;
+        } else {
+            writeln!(io::stdout(), "{}", comparison)?;
+        }
+    }
+    for timing in &timings {
+        if let TimingOutcome::Failed(e) = &timing.outcome {
+            writeln!(
+                io::stdout(),
+                "{}/day{:02}: failed: {}",
+                timing.year,
+                timing.day,
+                e
+            )?;
+            // counted above as not run when the baseline timed it
+            let total = format!("{}/day{:02}/total", timing.year, timing.day);
+            if !baseline.timings.contains_key(&total) {
+                regressions += 1;
+            }
+        }
+    }
+    writeln!(
+        io::stdout(),
+        "summary: {} days failing, not run or slower by more than {}%",
+        regressions,
+        args.threshold
+    )?;
+    Ok(regressions == 0)
+}
+
+fn verify_command(args: VerifyArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::new(&args.year, args.day)?.select(&registry)?;
+
+    let checks = verify(&solutions)?;
+    for check in checks.iter() {
+        writeln!(io::stdout(), "{}", check)?;
+    }
+    writeln!(io::stdout(), "summary: {}", Summary::new(&checks))?;
+    Ok(!checks.iter().any(|c| c.is_regression()))
+}
+
+fn fetch_command(args: FetchArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::new(&args.year, args.day)?.select(&registry)?;
+
+    let mut client = None;
+    for solution in solutions {
+        let fetched = fetch_input(&mut client, solution.year(), solution.day())?;
+        writeln!(
+            io::stdout(),
+            "{} day {:02}: {}",
+            solution.year(),
+            solution.day(),
+            fetched
+        )?;
+    }
+    Ok(true)
+}
+
+/// Answer of a part on the day's default input
+fn solve(year: u16, day: u8, part: Part) -> Result<Answer, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::Day(year, day).select(&registry)?;
+
+    match run(&solutions, &InputSource::Default, &[part])
+        .remove(0)
+        .outcome
+    {
+        DayOutcome::Solved(mut parts) => Ok(parts.remove(0).answer?),
+        DayOutcome::Failed(e) => Err(e.into()),
+        DayOutcome::MissingInput(path) => Err(format!("missing input `{}`", path.display()).into()),
+    }
+}
+
+fn submit_command(args: SubmitArgs) -> Result<bool, Box<dyn StdError>> {
+    let part = Part::try_from(args.part)?;
+    let answer = match args.answer {
+        // as typed or pasted, possibly with surrounding whitespace
+        Some(answer) => Answer::Text(answer.trim().to_string()),
+        None => solve(args.year, args.day, part)?,
+    };
+
+    let client = Client::from_env()?;
+    let outcome = submit(
+        &client,
+        &submissions_path(args.year),
+        args.year,
+        args.day,
+        part,
+        &answer,
+    )?;
+    writeln!(
+        io::stdout(),
+        "{} day {:02} part {}: `{}` is {}",
+        args.year,
+        args.day,
+        part,
+        answer,
+        outcome
+    )?;
+    Ok(matches!(outcome, Outcome::Correct | Outcome::AlreadySolved))
+}
+
+fn puzzle_command(args: PuzzleArgs) -> Result<bool, Box<dyn StdError>> {
+    let html = match args.html {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read `{}`: {}", path.display(), e))?,
+        None => Client::from_env()?.get(&format!("/{}/day/{}", args.year, args.day))?,
+    };
+
+    let puzzle = Puzzle::from_html(&html);
+    for path in puzzle.write(args.year, args.day)? {
+        writeln!(io::stdout(), "wrote `{}`", path.display())?;
+    }
+    Ok(true)
+}
+
+fn new_command(args: NewArgs) -> Result<bool, Box<dyn StdError>> {
+    let mut scaffold = Scaffold::new(&workspace_root());
+    let result = match args.day {
+        Some(day) => scaffold.new_day(args.year, day),
+        None => scaffold.new_year(args.year),
+    };
+
+    for change in scaffold.changes() {
+        writeln!(io::stdout(), "{}", change)?;
+    }
+    result?;
+    Ok(true)
+}
+
+fn watch_command(args: WatchArgs) -> Result<bool, Box<dyn StdError>> {
+    Target::Day(args.year, args.day).select(&registry())?;
+
+    Watch {
+        year: args.year,
+        day: args.day,
+        release: args.release,
+    }
+    .run()?;
+    Ok(true)
+}
+
+fn encrypt_command(args: EncryptArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solutions = Target::new(&args.year, args.day)?.select(&registry)?;
+
+    let key_file = key_file();
+    if args.generate_key {
+        Key::generate().save(&key_file)?;
+        writeln!(
+            io::stdout(),
+            "generated key `{}`, share it out of band",
+            key_file.display()
+        )?;
+    }
+    let key = Key::load(&key_file).map_err(|e| match e {
+        CryptError::NoKey(path) => format!(
+            "no key file `{}`, generate one with `--generate-key`",
+            path.display()
+        ),
+        e => e.to_string(),
+    })?;
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let variants = input_variants(year, day)
+            .into_iter()
+            .map(|variant| variant_input_path(year, day, &variant));
+        for path in iter::once(input_path(year, day)).chain(variants) {
+            if !path.is_file() {
+                continue;
+            }
+            let status = match encrypt_input(&key, &path)? {
+                true => "encrypted",
+                false => "unchanged",
+            };
+            writeln!(
+                io::stdout(),
+                "{} `{}`",
+                status,
+                encrypted_path(&path).display()
+            )?;
+        }
+    }
+    Ok(true)
+}
+
+fn generate_command(args: GenerateArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    Target::Day(args.year, args.day).select(&registry)?;
+    let generator = registry
+        .generator(args.year, args.day)
+        .ok_or_else(|| format!("no input generator for {} day {:02}", args.year, args.day))?;
+
+    write!(io::stdout(), "{}", generator.generate(args.seed, args.size))?;
+    Ok(true)
+}
+
+fn scale_command(args: ScaleArgs) -> Result<bool, Box<dyn StdError>> {
+    let registry = registry();
+    let solution = Target::Day(args.year, args.day).select(&registry)?[0];
+    let generator = registry
+        .generator(args.year, args.day)
+        .ok_or_else(|| format!("no input generator for {} day {:02}", args.year, args.day))?;
+
+    let parts = parts(args.part)?;
+    let scaling = scale(
+        solution,
+        generator,
+        &args.sizes,
+        args.seed,
+        &parts,
+        args.repeat,
+    );
+    write!(io::stdout(), "{}", scaling)?;
+    Ok(!scaling.failed())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run_command(args),
+        Command::Time(args) => time_command(args),
+        Command::Compare(args) => compare_command(args),
+        Command::Verify(args) => verify_command(args),
+        Command::Fetch(args) => fetch_command(args),
+        Command::Submit(args) => submit_command(args),
+        Command::Puzzle(args) => puzzle_command(args),
+        Command::New(args) => new_command(args),
+        Command::Watch(args) => watch_command(args),
+        Command::Encrypt(args) => encrypt_command(args),
+        Command::Generate(args) => generate_command(args),
+        Command::Scale(args) => scale_command(args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
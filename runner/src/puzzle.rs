@@ -0,0 +1,307 @@
+//! Puzzle description converted to Markdown, with its examples extracted
+//!
+//! The description of `dayXX` is stored as `src/dayXX.md` next to the solution,
+//! and every `<pre><code>` block as `inputs/examples/dayXX_N.txt`, numbered in
+//! order of appearance from 1, ready for `aoc_utils::read_example(day, n)`.
+//! Expected answers can then be added to the examples, see `aoc_utils::fixture`.
+
+use crate::year_dir;
+use aoc_utils::{example_path, Fixture};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub fn description_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join("src").join(format!("day{:02}.md", day))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+impl Puzzle {
+    /// Convert the `<article>` elements of a puzzle page
+    pub fn from_html(html: &str) -> Puzzle {
+        let mut converter = Converter::default();
+        for article in articles(html) {
+            for token in tokenize(article) {
+                converter.push(token);
+            }
+        }
+
+        Puzzle {
+            markdown: converter.markdown.trim_end().to_string() + "\n",
+            examples: converter.examples,
+        }
+    }
+
+    /// Write the description and the examples, returning the written files
+    ///
+    /// Examples already holding expected answers or parameters are kept, as
+    /// their header was written by hand.
+    pub fn write(&self, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+
+        let path = description_path(year, day);
+        fs::write(&path, &self.markdown)?;
+        written.push(path);
+
+        for (i, example) in self.examples.iter().enumerate() {
+            let path = example_path(year, day, i + 1);
+            if has_header(&path) {
+                continue;
+            }
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, example)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+fn has_header(path: &Path) -> bool {
+    Fixture::load(path)
+        .is_ok_and(|fixture| !fixture.expected.is_empty() || !fixture.params.is_empty())
+}
+
+/// Contents of each `<article>`, the whole page when there is none
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let end = rest[start..]
+            .find("</article>")
+            .map_or(rest.len(), |end| start + end);
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    if articles.is_empty() {
+        articles.push(html);
+    }
+    articles
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open { name: String, href: Option<String> },
+    Close(String),
+    Text(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // a tag left unterminated by a truncated page is dropped
+                let Some(end) = rest.find('>') else {
+                    break;
+                };
+                let tag = &rest[1..end];
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim().to_lowercase()));
+                } else if !tag.starts_with('!') {
+                    let name = tag
+                        .split(|c: char| c.is_whitespace() || c == '/')
+                        .next()
+                        .unwrap_or("")
+                        .to_lowercase();
+                    tokens.push(Token::Open {
+                        name,
+                        href: attribute(tag, "href").map(|href| decode_entities(&href)),
+                    });
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                tokens.push(Token::Text(decode_entities(&rest[..start])));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(decode_entities(rest)));
+                rest = "";
+            }
+        }
+    }
+    tokens
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(tag[start..end].to_string())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Markdown writer for the subset of HTML used by puzzle descriptions
+#[derive(Default)]
+struct Converter {
+    markdown: String,
+    examples: Vec<String>,
+    /// Raw text of the `<pre>` block being read
+    pre: Option<String>,
+    /// Depth of `<code>`, whose content is written verbatim
+    code: usize,
+    links: Vec<Option<String>>,
+}
+
+impl Converter {
+    fn push(&mut self, token: Token) {
+        if let Some(pre) = self.pre.as_mut() {
+            match token {
+                Token::Text(text) => pre.push_str(&text),
+                Token::Close(name) if name == "pre" => self.close_pre(),
+                _ => {}
+            }
+            return;
+        }
+
+        match token {
+            Token::Text(text) => self.text(&text),
+            Token::Open { name, href } => match name.as_str() {
+                "h2" => self.markdown.push_str("## "),
+                "pre" => self.pre = Some(String::new()),
+                "code" => {
+                    self.code += 1;
+                    if self.code == 1 {
+                        self.markdown.push('`');
+                    }
+                }
+                "em" if self.code == 0 => self.markdown.push('*'),
+                "li" => self.markdown.push_str("- "),
+                "a" => {
+                    self.markdown.push('[');
+                    self.links.push(href);
+                }
+                "br" => self.markdown.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" | "ul" => self.end_block(),
+                "li" => self.markdown.push('\n'),
+                "code" => {
+                    self.code = self.code.saturating_sub(1);
+                    if self.code == 0 {
+                        self.markdown.push('`');
+                    }
+                }
+                "em" if self.code == 0 => self.markdown.push('*'),
+                "a" => match self.links.pop().flatten() {
+                    Some(href) => self.markdown.push_str(&format!("]({})", href)),
+                    None => self.markdown.push(']'),
+                },
+                _ => {}
+            },
+        }
+    }
+
+    /// Text outside of `<pre>`, where line breaks are not significant
+    fn text(&mut self, text: &str) {
+        let at_line_start = self.markdown.is_empty() || self.markdown.ends_with('\n');
+        let text = text.replace('\n', " ");
+        if at_line_start {
+            self.markdown.push_str(text.trim_start());
+        } else {
+            self.markdown.push_str(&text);
+        }
+    }
+
+    /// End the current block with exactly one blank line
+    fn end_block(&mut self) {
+        let len = self.markdown.trim_end_matches('\n').len();
+        self.markdown.truncate(len);
+        self.markdown.push_str("\n\n");
+    }
+
+    fn close_pre(&mut self) {
+        let mut example = self.pre.take().unwrap_or_default();
+        if !example.ends_with('\n') {
+            example.push('\n');
+        }
+
+        self.markdown.push_str("```\n");
+        self.markdown.push_str(&example);
+        self.markdown.push_str("```\n\n");
+        self.examples.push(example);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The lists <em>aren't</em> very similar:</p>
+<pre><code>3   4
+4   3
+2   &lt;5
+</code></pre>
+<ul><li>Pair <code>1</code> with <code><em>3</em></code>.</li><li>See <a href="/2024/day/1/input" target="_blank">your input</a>.</li></ul>
+</article>
+<p>Your puzzle answer was <code>11</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again:</p>
+<pre><code>1 <em>2</em>
+3</code></pre>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_from_html() {
+        let puzzle = Puzzle::from_html(PAGE);
+
+        assert_eq!(
+            vec!["3   4\n4   3\n2   <5\n".to_string(), "1 2\n3\n".to_string()],
+            puzzle.examples
+        );
+        assert_eq!(
+            "## --- Day 1: Historian Hysteria ---\n\n\
+             The lists *aren't* very similar:\n\n\
+             ```\n3   4\n4   3\n2   <5\n```\n\n\
+             - Pair `1` with `3`.\n\
+             - See [your input](/2024/day/1/input).\n\n\
+             ## --- Part Two ---\n\n\
+             Again:\n\n\
+             ```\n1 2\n3\n```\n",
+            puzzle.markdown
+        );
+    }
+
+    #[test]
+    fn test_from_html_without_article() {
+        let puzzle = Puzzle::from_html("<p>Example:</p><pre><code>abc</code></pre>");
+
+        assert_eq!(vec!["abc\n".to_string()], puzzle.examples);
+    }
+
+    #[test]
+    fn test_from_html_truncated() {
+        for html in [
+            "<pre><code>abc</code></pre><",
+            "<pre><code>abc</code></pre><aé",
+        ] {
+            let puzzle = Puzzle::from_html(html);
+
+            assert_eq!(vec!["abc\n".to_string()], puzzle.examples);
+        }
+        assert_eq!(
+            "Example:\n",
+            Puzzle::from_html("<p>Example:</p><p").markdown
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Reporting of solution runs as text, JSON or JUnit XML
+//!
+//! Every format is built from the same [`PartRecord`]s, one per year, day and
+//! requested part, whatever the year crate the solution comes from.
+
+use crate::run::{DayOutcome, DayRun};
+use aoc_utils::{Answer, Part};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Junit,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "junit" => Ok(Format::Junit),
+            _ => Err(format!("unknown format `{}` (text, json or junit)", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    /// The solution does not solve this part yet
+    Unsolved,
+    /// Reading or parsing the input, or solving the part, failed
+    Error,
+    /// The input file does not exist
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Error => write!(f, "error"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Result of a part, as reported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// JSON number for integer answers, string for text ones, null otherwise
+    pub answer: Value,
+    pub answer_type: Option<&'static str>,
+    /// Solving time, null when the part was not run
+    pub duration_ns: Option<u64>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl PartRecord {
+    fn unrun(day_run: &DayRun, part: Part, status: Status, message: String) -> PartRecord {
+        PartRecord {
+            year: day_run.year,
+            day: day_run.day,
+            part: part.number(),
+            answer: Value::Null,
+            answer_type: None,
+            duration_ns: None,
+            status,
+            message: Some(message),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns.unwrap_or(0))
+    }
+}
+
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(v) => Value::from(*v),
+        Answer::UInt(v) => Value::from(*v),
+        Answer::Text(v) => Value::from(v.as_str()),
+        Answer::Unsolved => Value::Null,
+    }
+}
+
+/// One record per requested part of every day run
+pub fn records(day_runs: &[DayRun], parts: &[Part]) -> Vec<PartRecord> {
+    let mut records = Vec::new();
+    for day_run in day_runs {
+        match &day_run.outcome {
+            DayOutcome::MissingInput(path) => records.extend(parts.iter().map(|&part| {
+                let message = format!("missing input `{}`", path.display());
+                PartRecord::unrun(day_run, part, Status::Missing, message)
+            })),
+            DayOutcome::Failed(e) => records.extend(
+                parts
+                    .iter()
+                    .map(|&part| PartRecord::unrun(day_run, part, Status::Error, e.clone())),
+            ),
+            DayOutcome::Solved(part_runs) => records.extend(part_runs.iter().map(|p| {
+                let (status, message) = match &p.answer {
+                    Ok(answer) if answer.is_solved() => (Status::Ok, None),
+                    Ok(_) => (Status::Unsolved, None),
+                    Err(e) => (Status::Error, Some(e.clone())),
+                };
+                let answer = p.answer.as_ref().ok();
+                PartRecord {
+                    year: day_run.year,
+                    day: day_run.day,
+                    part: p.part.number(),
+                    answer: answer.map_or(Value::Null, answer_value),
+                    answer_type: answer.filter(|a| a.is_solved()).map(|a| a.type_name()),
+                    duration_ns: Some(p.duration.as_nanos() as u64),
+                    status,
+                    message,
+                }
+            })),
+        }
+    }
+    records
+}
+
+/// Write the day runs in the given format
+pub fn write_report(
+    out: &mut impl Write,
+    format: Format,
+    day_runs: &[DayRun],
+    parts: &[Part],
+) -> io::Result<()> {
+    match format {
+        Format::Text => day_runs
+            .iter()
+            .try_for_each(|day_run| write!(out, "{}", day_run)),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, &records(day_runs, parts))?;
+            writeln!(out)
+        }
+        Format::Junit => write_junit(out, &records(day_runs, parts)),
+    }
+}
+
+/// One test suite per year, one test case per part
+///
+/// Errors are reported as `<error>`, missing inputs and unsolved parts as
+/// `<skipped>`, and answers as the test case output.
+fn write_junit(out: &mut impl Write, records: &[PartRecord]) -> io::Result<()> {
+    let count =
+        |records: &[PartRecord], status| records.iter().filter(|r| r.status == status).count();
+    let seconds = |records: &[PartRecord]| {
+        records
+            .iter()
+            .map(|r| r.duration())
+            .sum::<Duration>()
+            .as_secs_f64()
+    };
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites name="aoc" tests="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+        records.len(),
+        count(records, Status::Error),
+        count(records, Status::Missing) + count(records, Status::Unsolved),
+        seconds(records)
+    )?;
+
+    for year_records in records.chunk_by(|a, b| a.year == b.year) {
+        writeln!(
+            out,
+            r#"  <testsuite name="{}" tests="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+            year_records[0].year,
+            year_records.len(),
+            count(year_records, Status::Error),
+            count(year_records, Status::Missing) + count(year_records, Status::Unsolved),
+            seconds(year_records)
+        )?;
+        for record in year_records {
+            write!(
+                out,
+                r#"    <testcase classname="{}.day{:02}" name="part {}" time="{:.6}">"#,
+                record.year,
+                record.day,
+                record.part,
+                record.duration().as_secs_f64()
+            )?;
+            let message = escape_xml(record.message.as_deref().unwrap_or_default());
+            match record.status {
+                Status::Ok => {
+                    let answer = match &record.answer {
+                        Value::String(text) => text.clone(),
+                        answer => answer.to_string(),
+                    };
+                    write!(out, "<system-out>{}</system-out>", escape_xml(&answer))?
+                }
+                Status::Unsolved => write!(out, r#"<skipped message="unsolved"/>"#)?,
+                Status::Missing => write!(out, r#"<skipped message="{}"/>"#, message)?,
+                Status::Error => write!(out, r#"<error message="{}"/>"#, message)?,
+            }
+            writeln!(out, "</testcase>")?;
+        }
+        writeln!(out, "  </testsuite>")?;
+    }
+    writeln!(out, "</testsuites>")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run::PartRun;
+    use std::path::PathBuf;
+
+    fn day_runs() -> Vec<DayRun> {
+        vec![
+            DayRun {
+                year: 2019,
+                day: 7,
+                outcome: DayOutcome::Solved(vec![
+                    PartRun {
+                        part: Part::One,
+                        answer: Ok(Answer::Int(43210)),
+                        duration: Duration::from_micros(1500),
+                    },
+                    PartRun {
+                        part: Part::Two,
+                        answer: Err("no <amplifier> left".to_string()),
+                        duration: Duration::from_micros(20),
+                    },
+                ]),
+            },
+            DayRun {
+                year: 2024,
+                day: 1,
+                outcome: DayOutcome::MissingInput(PathBuf::from("inputs/day01.txt")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_records() {
+        let records = records(&day_runs(), &Part::ALL);
+
+        assert_eq!(
+            vec![
+                (2019, 7, 1, Status::Ok),
+                (2019, 7, 2, Status::Error),
+                (2024, 1, 1, Status::Missing),
+                (2024, 1, 2, Status::Missing),
+            ],
+            records
+                .iter()
+                .map(|r| (r.year, r.day, r.part, r.status))
+                .collect::<Vec<(u16, u8, u8, Status)>>()
+        );
+        assert_eq!(Value::from(43210), records[0].answer);
+        assert_eq!(Some("int"), records[0].answer_type);
+        assert_eq!(Some(1_500_000), records[0].duration_ns);
+        assert_eq!(None, records[2].duration_ns);
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        write_report(&mut out, Format::Json, &day_runs(), &Part::ALL).unwrap();
+
+        let json: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(4, json.as_array().unwrap().len());
+        assert_eq!(
+            serde_json::json!({
+                "year": 2019,
+                "day": 7,
+                "part": 1,
+                "answer": 43210,
+                "answer_type": "int",
+                "duration_ns": 1500000,
+                "status": "ok"
+            }),
+            json[0]
+        );
+        assert_eq!("no <amplifier> left", json[1]["message"]);
+    }
+
+    #[test]
+    fn test_junit() {
+        let mut out = Vec::new();
+        write_report(&mut out, Format::Junit, &day_runs(), &Part::ALL).unwrap();
+        let xml = String::from_utf8(out).unwrap();
+
+        assert!(xml.contains(r#"<testsuites name="aoc" tests="4" errors="1" skipped="2""#));
+        assert!(xml.contains(r#"<testsuite name="2019" tests="2" errors="1" skipped="0""#));
+        assert!(xml.contains(
+            r#"<testcase classname="2019.day07" name="part 1" time="0.001500"><system-out>43210</system-out></testcase>"#
+        ));
+        assert!(xml.contains(r#"<error message="no &lt;amplifier&gt; left"/>"#));
+        assert!(xml.contains(r#"<skipped message="missing input `inputs/day01.txt`"/>"#));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!(Ok(Format::Junit), "junit".parse());
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
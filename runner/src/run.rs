@@ -0,0 +1,488 @@
+//! Selection and execution of registered solutions
+
+use aoc_utils::crypt::{self, input_exists};
+use aoc_utils::{input_path, variant_input_path, Answer, DynSolution, Params, Part, Registry};
+use std::any::Any;
+use std::fmt;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Solutions selected on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Target {
+    pub fn new(year: &str, day: Option<u8>) -> Result<Target, String> {
+        match (year, day) {
+            ("all", None) => Ok(Target::All),
+            ("all", Some(_)) => Err("a day cannot be given with `all`".to_string()),
+            (year, day) => {
+                let year = year
+                    .parse::<u16>()
+                    .map_err(|_| format!("invalid year: `{}`", year))?;
+                Ok(match day {
+                    Some(day) => Target::Day(year, day),
+                    None => Target::Year(year),
+                })
+            }
+        }
+    }
+
+    /// Registered solutions matching the target, ordered by year then day
+    pub fn select<'a>(&self, registry: &'a Registry) -> Result<Vec<&'a dyn DynSolution>, String> {
+        let selected: Vec<&dyn DynSolution> = match *self {
+            Target::All => registry.iter().collect(),
+            Target::Year(year) => registry.year(year).collect(),
+            Target::Day(year, day) => registry.get(year, day).into_iter().collect(),
+        };
+
+        if selected.is_empty() {
+            return Err(format!("no solution registered for {}", self));
+        }
+        Ok(selected)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Target::All => write!(f, "all years"),
+            Target::Year(year) => write!(f, "{}", year),
+            Target::Day(year, day) => write!(f, "{} day {:02}", year, day),
+        }
+    }
+}
+
+/// Where the puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayXX.txt` of the year's input directory
+    Default,
+    /// Alternative input `dayXX.<variant>.txt` of the year's input directory
+    Variant(String),
+    Stdin,
+    File(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            "" => Err("empty input path".to_string()),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+/// Conventional input file of a day, see [`aoc_utils::paths`]
+pub fn default_input_path(year: u16, day: u8) -> PathBuf {
+    input_path(year, day)
+}
+
+/// Outcome of a single part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    /// Time spent solving the part, parsing excluded
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOutcome {
+    /// The input file does not exist
+    MissingInput(PathBuf),
+    /// The input could not be read or parsed
+    Failed(String),
+    Solved(Vec<PartRun>),
+}
+
+/// Outcome of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: DayOutcome,
+}
+
+impl DayRun {
+    /// Whether reading, parsing or any part failed
+    pub fn has_failed(&self) -> bool {
+        match &self.outcome {
+            DayOutcome::MissingInput(_) => false,
+            DayOutcome::Failed(_) => true,
+            DayOutcome::Solved(parts) => parts.iter().any(|p| p.answer.is_err()),
+        }
+    }
+}
+
+impl fmt::Display for DayRun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = format!("{} day {:02}", self.year, self.day);
+        match &self.outcome {
+            DayOutcome::MissingInput(path) => {
+                writeln!(f, "{}: missing input `{}`", prefix, path.display())
+            }
+            DayOutcome::Failed(e) => writeln!(f, "{}: error: {}", prefix, e),
+            DayOutcome::Solved(parts) => {
+                for p in parts {
+                    match &p.answer {
+                        // multi-line answers (e.g. rendered images) start on their own line
+                        Ok(Answer::Text(text)) if text.contains('\n') => {
+                            writeln!(f, "{} part {}:\n{}", prefix, p.part, text.trim_matches('\n'))?
+                        }
+                        Ok(answer) => writeln!(f, "{} part {}: {}", prefix, p.part, answer)?,
+                        Err(e) => writeln!(f, "{} part {}: error: {}", prefix, p.part, e)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Read the input of a day, `Err` holding the outcome to report instead
+pub fn read_input(source: &InputSource, year: u16, day: u8) -> Result<String, DayOutcome> {
+    match source {
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| DayOutcome::Failed(format!("cannot read from stdin: {}", e)))?;
+            Ok(input)
+        }
+        InputSource::Default => read_input_file(default_input_path(year, day)),
+        InputSource::Variant(variant) => read_input_file(variant_input_path(year, day, variant)),
+        InputSource::File(path) => read_input_file(path.clone()),
+    }
+}
+
+/// Content of an input file, decrypted from its `.enc` counterpart when needed
+fn read_input_file(path: PathBuf) -> Result<String, DayOutcome> {
+    if !input_exists(&path) {
+        return Err(DayOutcome::MissingInput(path));
+    }
+    crypt::read_input_file(&path).map_err(|e| DayOutcome::Failed(e.to_string()))
+}
+
+/// Puzzle parameter given as `name=value` on the command line
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("invalid parameter `{}`, expected `name=value`", s)),
+    }
+}
+
+/// Parse the input and solve the requested parts
+pub fn run_day(solution: &dyn DynSolution, input: &str, parts: &[Part]) -> DayRun {
+    run_day_with(solution, input, &Params::new(), parts)
+}
+
+/// Parse the input with puzzle parameters and solve the requested parts
+pub fn run_day_with(
+    solution: &dyn DynSolution,
+    input: &str,
+    params: &Params,
+    parts: &[Part],
+) -> DayRun {
+    let outcome = match solution.parse_with(input, params) {
+        Ok(parsed) => DayOutcome::Solved(
+            parts
+                .iter()
+                .map(|&part| {
+                    let start = Instant::now();
+                    let answer = solution
+                        .solve(part, parsed.as_ref())
+                        .map_err(|e| e.to_string());
+                    PartRun {
+                        part,
+                        answer,
+                        duration: start.elapsed(),
+                    }
+                })
+                .collect(),
+        ),
+        Err(e) => DayOutcome::Failed(format!("cannot parse input: {}", e)),
+    };
+
+    DayRun {
+        year: solution.year(),
+        day: solution.day(),
+        outcome,
+    }
+}
+
+/// Failure message of a caught panic
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    format!("panicked: {}", message)
+}
+
+/// Read the input of a solution and run it, a panic being reported as a failure
+fn run_solution(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    params: &Params,
+    parts: &[Part],
+) -> DayRun {
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        match read_input(source, solution.year(), solution.day()) {
+            Ok(input) => run_day_with(solution, &input, params, parts).outcome,
+            Err(outcome) => outcome,
+        }
+    }))
+    .unwrap_or_else(|payload| DayOutcome::Failed(panic_message(payload.as_ref())));
+
+    DayRun {
+        year: solution.year(),
+        day: solution.day(),
+        outcome,
+    }
+}
+
+/// Run every selected solution on its input
+pub fn run(solutions: &[&dyn DynSolution], source: &InputSource, parts: &[Part]) -> Vec<DayRun> {
+    solutions
+        .iter()
+        .map(|solution| run_solution(*solution, source, &Params::new(), parts))
+        .collect()
+}
+
+/// Run every selected solution on its input with puzzle parameters, on at
+/// most `jobs` threads
+///
+/// Days are handed out one at a time to the first idle thread, and their
+/// outcomes returned in the order of `solutions` whatever the completion order.
+pub fn run_parallel(
+    solutions: &[&dyn DynSolution],
+    source: &InputSource,
+    params: &Params,
+    parts: &[Part],
+    jobs: usize,
+) -> Vec<DayRun> {
+    let jobs = jobs.clamp(1, solutions.len().max(1));
+    if jobs == 1 {
+        return solutions
+            .iter()
+            .map(|solution| run_solution(*solution, source, params, parts))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let day_runs: Mutex<Vec<Option<DayRun>>> = Mutex::new(vec![None; solutions.len()]);
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(solution) = solutions.get(i) else {
+                    break;
+                };
+                let day_run = run_solution(*solution, source, params, parts);
+                day_runs.lock().expect("no thread panics holding the lock")[i] = Some(day_run);
+            });
+        }
+    });
+
+    day_runs
+        .into_inner()
+        .expect("no thread panics holding the lock")
+        .into_iter()
+        .map(|day_run| day_run.expect("every selected solution is run"))
+        .collect()
+}
+
+/// Counts of a run, listing what failed
+pub struct RunSummary<'a> {
+    pub day_runs: &'a [DayRun],
+}
+
+impl fmt::Display for RunSummary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let missing = self
+            .day_runs
+            .iter()
+            .filter(|d| matches!(d.outcome, DayOutcome::MissingInput(_)))
+            .count();
+        let failures: Vec<String> = self
+            .day_runs
+            .iter()
+            .flat_map(|day_run| {
+                let prefix = format!("{} day {:02}", day_run.year, day_run.day);
+                match &day_run.outcome {
+                    DayOutcome::MissingInput(_) => vec![],
+                    DayOutcome::Failed(_) => vec![prefix],
+                    DayOutcome::Solved(parts) => parts
+                        .iter()
+                        .filter(|p| p.answer.is_err())
+                        .map(|p| format!("{} part {}", prefix, p.part))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        write!(
+            f,
+            "{} days run, {} missing inputs, ",
+            self.day_runs.len(),
+            missing
+        )?;
+        match failures.len() {
+            0 => write!(f, "no failure"),
+            n => write!(f, "{} failed: {}", n, failures.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_target_new() {
+        assert_eq!(Ok(Target::All), Target::new("all", None));
+        assert_eq!(Ok(Target::Year(2020)), Target::new("2020", None));
+        assert_eq!(Ok(Target::Day(2019, 7)), Target::new("2019", Some(7)));
+        assert!(Target::new("all", Some(1)).is_err());
+        assert!(Target::new("twenty", None).is_err());
+    }
+
+    #[test]
+    fn test_target_select() {
+        let registry = registry();
+
+        assert_eq!(registry.len(), Target::All.select(&registry).unwrap().len());
+        assert_eq!(10, Target::Year(2020).select(&registry).unwrap().len());
+        assert_eq!(
+            vec![(2019, 7)],
+            Target::Day(2019, 7)
+                .select(&registry)
+                .unwrap()
+                .iter()
+                .map(|s| (s.year(), s.day()))
+                .collect::<Vec<(u16, u8)>>()
+        );
+        assert!(Target::Year(2015).select(&registry).is_err());
+        assert!(Target::Day(2024, 25).select(&registry).is_err());
+    }
+
+    #[test]
+    fn test_run_day() {
+        let registry = registry();
+        let solution = registry.get(2024, 1).unwrap();
+
+        let day_run = run_day(solution, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n", &[Part::Two]);
+
+        match &day_run.outcome {
+            DayOutcome::Solved(parts) => assert_eq!(
+                vec![(Part::Two, Ok(Answer::Int(31)))],
+                parts
+                    .iter()
+                    .map(|p| (p.part, p.answer.clone()))
+                    .collect::<Vec<(Part, Result<Answer, String>)>>()
+            ),
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+        assert!(!day_run.has_failed());
+        assert!(run_day(solution, "3   x\n", &Part::ALL).has_failed());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            Ok(("steps".to_string(), "10".to_string())),
+            parse_param("steps=10")
+        );
+        assert_eq!(
+            Ok(("target".to_string(), "a=b".to_string())),
+            parse_param(" target = a=b")
+        );
+        assert!(parse_param("steps").is_err());
+        assert!(parse_param("=10").is_err());
+    }
+
+    #[test]
+    fn test_run_parallel_keeps_order() {
+        let registry = registry();
+        let solutions = Target::All.select(&registry).unwrap();
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+
+        assert_eq!(
+            run(&solutions, &source, &Part::ALL),
+            run_parallel(&solutions, &source, &Params::new(), &Part::ALL, 4)
+        );
+    }
+
+    #[test]
+    fn test_run_summary() {
+        let day_runs = vec![
+            DayRun {
+                year: 2019,
+                day: 1,
+                outcome: DayOutcome::MissingInput(PathBuf::from("day01.txt")),
+            },
+            DayRun {
+                year: 2019,
+                day: 2,
+                outcome: DayOutcome::Failed("panicked: oops".to_string()),
+            },
+            DayRun {
+                year: 2019,
+                day: 3,
+                outcome: DayOutcome::Solved(vec![PartRun {
+                    part: Part::Two,
+                    answer: Err("no path".to_string()),
+                    duration: Duration::ZERO,
+                }]),
+            },
+        ];
+
+        assert_eq!(
+            "3 days run, 1 missing inputs, 2 failed: 2019 day 02, 2019 day 03 part 2",
+            RunSummary {
+                day_runs: &day_runs
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "1 days run, 1 missing inputs, no failure",
+            RunSummary {
+                day_runs: &day_runs[..1]
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_missing_input_is_not_a_failure() {
+        let day_run = DayRun {
+            year: 2024,
+            day: 1,
+            outcome: read_input_file(PathBuf::from("does/not/exist.txt")).unwrap_err(),
+        };
+
+        assert!(!day_run.has_failed());
+        assert_eq!(
+            "2024 day 01: missing input `does/not/exist.txt`\n",
+            day_run.to_string()
+        );
+    }
+}
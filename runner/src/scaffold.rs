@@ -0,0 +1,563 @@
+//! Scaffolding of new year crates and days
+//!
+//! Every step is idempotent: existing files are never overwritten, and shared
+//! files (workspace and year manifests, `lib.rs`) only get the missing lines.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("invalid {what}: {value}")]
+    InvalidArgument { what: &'static str, value: u16 },
+    #[error("cannot write `{path}`: {source}")]
+    CannotWrite { path: PathBuf, source: io::Error },
+    #[error("cannot update `{path}`: {message}")]
+    CannotUpdate { path: PathBuf, message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Created(PathBuf),
+    Updated(PathBuf),
+    /// Already up to date, left untouched
+    Unchanged(PathBuf),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Created(path) => write!(f, "created   {}", path.display()),
+            Change::Updated(path) => write!(f, "updated   {}", path.display()),
+            Change::Unchanged(path) => write!(f, "unchanged {}", path.display()),
+        }
+    }
+}
+
+/// Files of a workspace rooted at `root`
+pub struct Scaffold {
+    root: PathBuf,
+    changes: Vec<Change>,
+}
+
+impl Scaffold {
+    pub fn new(root: &Path) -> Scaffold {
+        Scaffold {
+            root: root.to_path_buf(),
+            changes: Vec::new(),
+        }
+    }
+
+    /// Changes made so far, in order
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Create the year crate, add it to the workspace and to the runner registry
+    pub fn new_year(&mut self, year: u16) -> Result<(), ScaffoldError> {
+        if !(2015..=2099).contains(&year) {
+            return Err(ScaffoldError::InvalidArgument {
+                what: "year",
+                value: year,
+            });
+        }
+        let year_dir = self.root.join("years").join(year.to_string());
+
+        self.create(&year_dir.join("Cargo.toml"), &year_manifest(year))?;
+        self.create(&year_dir.join("src").join("lib.rs"), &year_lib(year))?;
+        self.create(&year_dir.join("answers.toml"), &year_answers(year))?;
+        self.create(&year_dir.join("build.rs"), YEAR_BUILD)?;
+        self.create(
+            &year_dir.join("benches").join("benchmarks.rs"),
+            &year_bench(year),
+        )?;
+
+        self.update(&self.root.join("Cargo.toml"), |content| {
+            insert_sorted_line(
+                content,
+                &format!("  \"years/{}\",", year),
+                |line| line.trim_start().starts_with("\"years/"),
+                "members = [",
+            )
+        })?;
+        self.update(&self.root.join("runner").join("Cargo.toml"), |content| {
+            insert_sorted_line(
+                content,
+                &format!("aoc_{} = {{ path = \"../years/{}\" }}", year, year),
+                |line| line.starts_with("aoc_20"),
+                "aoc_utils = { path = \"../utils\" }",
+            )
+        })?;
+        self.update(
+            &self.root.join("runner").join("src").join("lib.rs"),
+            |content| {
+                insert_sorted_line(
+                    content,
+                    &format!("    aoc_{}::register(&mut registry);", year),
+                    |line| line.trim_start().starts_with("aoc_20"),
+                    "    let mut registry = Registry::new();",
+                )
+            },
+        )
+    }
+
+    /// Add a day to its year crate, creating the year first when needed
+    pub fn new_day(&mut self, year: u16, day: u8) -> Result<(), ScaffoldError> {
+        if !(1..=25).contains(&day) {
+            return Err(ScaffoldError::InvalidArgument {
+                what: "day",
+                value: day as u16,
+            });
+        }
+        self.new_year(year)?;
+        let year_dir = self.root.join("years").join(year.to_string());
+
+        self.create(
+            &year_dir.join("src").join(format!("day{:02}.rs", day)),
+            &day_module(year, day),
+        )?;
+        self.create(
+            &year_dir
+                .join("src")
+                .join("bin")
+                .join(format!("day{:02}.rs", day)),
+            &day_bin(year, day),
+        )?;
+
+        self.update(&year_dir.join("Cargo.toml"), |content| {
+            Ok(add_bin_target(content, year, day))
+        })?;
+        self.update(&year_dir.join("src").join("lib.rs"), |content| {
+            let with_module = insert_sorted_line(
+                content,
+                &format!("pub mod day{:02};", day),
+                |line| line.starts_with("pub mod day"),
+                "// Day modules",
+            )?;
+            let current = with_module.as_deref().unwrap_or(content);
+            Ok(add_registration(current, day)?.or(with_module))
+        })
+    }
+
+    /// Create a file, keeping it when it already exists
+    fn create(&mut self, path: &Path, content: &str) -> Result<(), ScaffoldError> {
+        let cannot_write = |source| ScaffoldError::CannotWrite {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(cannot_write)?;
+        }
+
+        match OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(mut file) => {
+                file.write_all(content.as_bytes()).map_err(cannot_write)?;
+                self.changes.push(Change::Created(path.to_path_buf()));
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                self.changes.push(Change::Unchanged(path.to_path_buf()));
+            }
+            Err(e) => return Err(cannot_write(e)),
+        }
+        Ok(())
+    }
+
+    /// Rewrite a file with `edit`, which returns `None` when nothing is missing
+    fn update(
+        &mut self,
+        path: &Path,
+        edit: impl FnOnce(&str) -> Result<Option<String>, String>,
+    ) -> Result<(), ScaffoldError> {
+        let cannot_update = |message| ScaffoldError::CannotUpdate {
+            path: path.to_path_buf(),
+            message,
+        };
+        let content = fs::read_to_string(path).map_err(|e| cannot_update(e.to_string()))?;
+
+        match edit(&content).map_err(cannot_update)? {
+            Some(updated) => {
+                fs::write(path, updated).map_err(|source| ScaffoldError::CannotWrite {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+                self.changes.push(Change::Updated(path.to_path_buf()));
+            }
+            None => self.changes.push(Change::Unchanged(path.to_path_buf())),
+        }
+        Ok(())
+    }
+}
+
+/// Insert `line` among the lines matching `is_peer`, in sorted order, or right
+/// after the `anchor` line when there is no peer yet
+fn insert_sorted_line(
+    content: &str,
+    line: &str,
+    is_peer: impl Fn(&str) -> bool,
+    anchor: &str,
+) -> Result<Option<String>, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    if lines.iter().any(|l| l.trim() == line.trim()) {
+        return Ok(None);
+    }
+
+    let peers: Vec<usize> = (0..lines.len()).filter(|&i| is_peer(lines[i])).collect();
+    let index = match (peers.iter().find(|&&i| lines[i] > line), peers.last()) {
+        (Some(&next), _) => next,
+        (None, Some(&last)) => last + 1,
+        (None, None) => {
+            lines
+                .iter()
+                .position(|l| l.trim() == anchor.trim())
+                .ok_or_else(|| format!("cannot find `{}`", anchor.trim()))?
+                + 1
+        }
+    };
+    lines.insert(index, line);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// Add the `[[bin]]` target of a day to the year manifest
+///
+/// Benchmarks need no target per day, the year's one iterating its registry.
+fn add_bin_target(content: &str, year: u16, day: u8) -> Option<String> {
+    let bin = format!(
+        "[[bin]]\nname = \"{}_day{:02}\"\npath = \"src/bin/day{:02}.rs\"",
+        year, day, day
+    );
+    if content.contains(&bin) {
+        return None;
+    }
+    Some(format!("{}\n\n{}\n", content.trim_end(), bin))
+}
+
+/// Add a day to the `register` function of a year, keeping days in order
+fn add_registration(content: &str, day: u8) -> Result<Option<String>, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub fn register("))
+        .ok_or("cannot find `pub fn register`")?;
+    let end = if lines[start].ends_with('}') {
+        start
+    } else {
+        (start..lines.len())
+            .find(|&i| lines[i] == "}")
+            .ok_or("cannot find the end of `register`")?
+    };
+    // Statements following the chain of registrations, e.g. of input generators
+    let chain_end = (start + 1..end)
+        .find(|&i| lines[i].ends_with(';'))
+        .unwrap_or(end);
+    let rest = &lines[(chain_end + 1).min(end)..end];
+
+    let mut days: Vec<String> = lines[start..=chain_end]
+        .join("\n")
+        .split("register::<")
+        .skip(1)
+        .filter_map(|s| s.split(">()").next())
+        .map(|s| s.to_string())
+        .collect();
+    let registered = format!("day{:02}::Day{:02}", day, day);
+    if days.contains(&registered) {
+        return Ok(None);
+    }
+    days.push(registered);
+    days.sort();
+
+    let mut function = register_function(&days);
+    if !rest.is_empty() {
+        function.truncate(function.len() - 1);
+        function.push_str(&rest.join("\n"));
+        function.push_str("\n}");
+    }
+
+    let mut updated: Vec<String> = lines[..start].iter().map(|l| l.to_string()).collect();
+    updated.push(function);
+    updated.extend(lines[end + 1..].iter().map(|l| l.to_string()));
+    Ok(Some(updated.join("\n") + "\n"))
+}
+
+/// `register` function of the given `dayXX::DayXX` solutions, as formatted by rustfmt
+fn register_function(days: &[String]) -> String {
+    match days {
+        [] => "pub fn register(_registry: &mut Registry) {}".to_string(),
+        [day] => format!(
+            "pub fn register(registry: &mut Registry) {{\n    registry.register::<{}>();\n}}",
+            day
+        ),
+        days => format!(
+            "pub fn register(registry: &mut Registry) {{\n    registry\n{};\n}}",
+            days.iter()
+                .map(|day| format!("        .register::<{}>()", day))
+                .collect::<Vec<String>>()
+                .join("\n")
+        ),
+    }
+}
+
+fn year_manifest(year: u16) -> String {
+    format!(
+        r#"[package]
+name = "aoc_{year}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc_utils = {{ path = "../../utils" }}
+
+[build-dependencies]
+aoc_utils = {{ path = "../../utils" }}
+
+[dev-dependencies]
+aoc_utils = {{ path = "../../utils", features = ["bench"] }}
+criterion = {{ version = "0.5.1", features = ["html_reports"] }}
+
+[[bench]]
+name = "benchmarks"
+harness = false
+"#
+    )
+}
+
+fn year_lib(year: u16) -> String {
+    format!(
+        r#"//! Advent of Code {year} Solutions
+//!
+//! This module contains all solutions for Advent of Code {year}.
+//! Each day's logic is in a separate module, and binaries are in src/bin/
+
+// Re-export common utilities from aoc_utils
+pub use aoc_utils::*;
+
+// Day modules
+
+/// Add every {year} solution to the registry
+{}
+
+#[cfg(test)]
+mod example_tests {{
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}}
+
+#[cfg(test)]
+mod input_tests {{
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+}}
+"#,
+        register_function(&[])
+    )
+}
+
+/// Build script generating a test per example and per known answer,
+/// see `aoc_utils::fixture` and `aoc_utils::regression`
+const YEAR_BUILD: &str = "fn main() {
+    aoc_utils::fixture::write_example_tests();
+    aoc_utils::regression::write_input_tests();
+}
+";
+
+fn year_answers(year: u16) -> String {
+    format!(
+        r#"# Accepted answers of {year}, checked by `cargo run -p aoc -- verify {year}`
+#
+# [day01]
+# part1 = 123
+# part2 = "text answer"
+"#
+    )
+}
+
+fn day_module(year: u16, day: u8) -> String {
+    format!(
+        r#"use aoc_utils::{{Answer, Solution, SolutionError}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {{
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<Answer, SolutionError> {{
+        Ok(Answer::Unsolved)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_part1() {{
+        let input = Day{day:02}::parse("").unwrap();
+
+        assert_eq!(Answer::Unsolved, Day{day:02}::part1(&input).unwrap());
+    }}
+}}
+"#
+    )
+}
+
+fn day_bin(year: u16, day: u8) -> String {
+    format!(
+        r#"use aoc_{year}::day{day:02}::Day{day:02};
+use aoc_{year}::{{Solution, SolutionError}};
+use std::io::{{self, Read, Write}};
+
+fn main() -> Result<(), SolutionError> {{
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let input = Day{day:02}::parse(&input)?;
+    writeln!(io::stdout(), "Part 1: {{}}", Day{day:02}::part1(&input)?)?;
+    writeln!(io::stdout(), "Part 2: {{}}", Day{day:02}::part2(&input)?)?;
+    Ok(())
+}}
+"#
+    )
+}
+
+/// Benchmarks of every registered day, see `aoc_utils::bench`
+fn year_bench(year: u16) -> String {
+    format!(
+        r#"use aoc_utils::bench::bench_registry;
+use aoc_utils::Registry;
+use criterion::{{criterion_group, criterion_main, Criterion}};
+
+fn bench_{year}(c: &mut Criterion) {{
+    let mut registry = Registry::new();
+    aoc_{year}::register(&mut registry);
+    bench_registry(c, &registry);
+}}
+
+criterion_group!(benches, bench_{year});
+criterion_main!(benches);
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// Minimal workspace holding the files edited by the scaffolding
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("runner").join("src")).unwrap();
+
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n  \"utils\",\n  \"runner\",\n  \"years/2024\",\n]\nresolver = \"2\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner").join("Cargo.toml"),
+            "[dependencies]\naoc_utils = { path = \"../utils\" }\naoc_2024 = { path = \"../years/2024\" }\nclap = \"4.5\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("runner").join("src").join("lib.rs"),
+            "pub fn registry() -> Registry {\n    let mut registry = Registry::new();\n    aoc_2024::register(&mut registry);\n    registry\n}\n",
+        )
+        .unwrap();
+        root
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace("day");
+        let mut scaffold = Scaffold::new(&root);
+
+        scaffold.new_day(2025, 3).unwrap();
+        scaffold.new_day(2025, 1).unwrap();
+
+        assert!(read(&root, "Cargo.toml").contains("  \"years/2024\",\n  \"years/2025\",\n]"));
+        assert!(read(&root, "runner/Cargo.toml").contains(
+            "aoc_2024 = { path = \"../years/2024\" }\naoc_2025 = { path = \"../years/2025\" }\n"
+        ));
+        assert!(read(&root, "runner/src/lib.rs").contains(
+            "    aoc_2024::register(&mut registry);\n    aoc_2025::register(&mut registry);\n"
+        ));
+        assert!(read(&root, "years/2025/src/lib.rs").contains(
+            "// Day modules\npub mod day01;\npub mod day03;\n\n\
+             /// Add every 2025 solution to the registry\n\
+             pub fn register(registry: &mut Registry) {\n    registry\n        \
+             .register::<day01::Day01>()\n        .register::<day03::Day03>();\n}\n\n\
+             #[cfg(test)]\nmod example_tests {"
+        ));
+        let manifest = read(&root, "years/2025/Cargo.toml");
+        assert!(manifest.contains("[[bin]]\nname = \"2025_day03\"\npath = \"src/bin/day03.rs\""));
+        assert!(manifest.contains("[[bench]]\nname = \"benchmarks\"\nharness = false"));
+        assert!(!manifest.contains("benches/day01.rs"));
+        assert!(manifest.contains("[build-dependencies]\naoc_utils = { path = \"../../utils\" }"));
+        assert!(!manifest.contains("thiserror"));
+        let build = read(&root, "years/2025/build.rs");
+        assert!(build.contains("write_example_tests()") && build.contains("write_input_tests()"));
+        assert!(read(&root, "years/2025/src/day03.rs").contains("const DAY: u8 = 3;"));
+        assert!(root.join("years/2025/src/bin/day01.rs").exists());
+        assert!(read(&root, "years/2025/benches/benchmarks.rs")
+            .contains("aoc_2025::register(&mut registry);"));
+    }
+
+    #[test]
+    fn test_new_day_is_idempotent() {
+        let root = workspace("idempotent");
+        Scaffold::new(&root).new_day(2025, 1).unwrap();
+        fs::write(root.join("years/2025/src/day01.rs"), "// solved").unwrap();
+
+        let mut scaffold = Scaffold::new(&root);
+        scaffold.new_day(2025, 1).unwrap();
+
+        assert!(scaffold
+            .changes()
+            .iter()
+            .all(|c| matches!(c, Change::Unchanged(_))));
+        assert_eq!("// solved", read(&root, "years/2025/src/day01.rs"));
+        assert!(read(&root, "years/2025/src/lib.rs")
+            .contains("pub fn register(registry: &mut Registry) {\n    registry.register::<day01::Day01>();\n}"));
+    }
+
+    #[test]
+    fn test_add_registration_keeps_generators() {
+        let lib = "use aoc_utils::Registry;\n\n\
+                   pub fn register(registry: &mut Registry) {\n    \
+                   registry.register::<day02::Day02>();\n    \
+                   registry.register_generator::<day02::Day02>();\n}\n";
+
+        assert_eq!(
+            Some(
+                "use aoc_utils::Registry;\n\n\
+                 pub fn register(registry: &mut Registry) {\n    registry\n        \
+                 .register::<day01::Day01>()\n        .register::<day02::Day02>();\n    \
+                 registry.register_generator::<day02::Day02>();\n}\n"
+                    .to_string()
+            ),
+            add_registration(lib, 1).unwrap()
+        );
+        assert_eq!(None, add_registration(lib, 2).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let root = workspace("invalid");
+
+        assert!(Scaffold::new(&root).new_year(1999).is_err());
+        assert!(Scaffold::new(&root).new_day(2025, 26).is_err());
+        assert!(!root.join("years/2025").exists());
+    }
+}
@@ -0,0 +1,254 @@
+//! Scaling of solutions with the size of their generated inputs
+
+use crate::run::panic_message;
+use crate::timing::{format_bytes, format_duration, time_day, TimingOutcome};
+use aoc_utils::generate::InputGenerator;
+use aoc_utils::{DynSolution, Part};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+/// Timing of a solution on an input of a given size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaleRow {
+    pub size: usize,
+    /// Length of the generated input
+    pub bytes: usize,
+    pub outcome: TimingOutcome,
+}
+
+/// Timings of a solution on generated inputs of growing sizes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaling {
+    pub year: u16,
+    pub day: u8,
+    /// What the sizes count
+    pub unit: &'static str,
+    pub seed: u64,
+    pub parts: Vec<Part>,
+    pub rows: Vec<ScaleRow>,
+}
+
+impl Scaling {
+    pub fn failed(&self) -> bool {
+        self.rows
+            .iter()
+            .any(|row| matches!(row.outcome, TimingOutcome::Failed(_)))
+    }
+}
+
+/// Time a solution on an input generated for each size, stopping at the first failure
+///
+/// A panic, e.g. an overflow on a large input, is reported as a failure.
+pub fn scale(
+    solution: &dyn DynSolution,
+    generator: &InputGenerator,
+    sizes: &[usize],
+    seed: u64,
+    parts: &[Part],
+    repeats: usize,
+) -> Scaling {
+    let mut rows = Vec::with_capacity(sizes.len());
+    for &size in sizes {
+        let input = generator.generate(seed, size);
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            time_day(solution, &input, parts, repeats)
+        }))
+        .unwrap_or_else(|payload| TimingOutcome::Failed(panic_message(payload.as_ref())));
+
+        let failed = matches!(outcome, TimingOutcome::Failed(_));
+        rows.push(ScaleRow {
+            size,
+            bytes: input.len(),
+            outcome,
+        });
+        if failed {
+            break;
+        }
+    }
+
+    Scaling {
+        year: solution.year(),
+        day: solution.day(),
+        unit: generator.size,
+        seed,
+        parts: parts.to_vec(),
+        rows,
+    }
+}
+
+/// Exponent `k` such that the time grows as `n^k` between two sizes
+pub fn exponent(from: (usize, Duration), to: (usize, Duration)) -> Option<f64> {
+    let ((n1, t1), (n2, t2)) = (from, to);
+    if n1 == 0 || n2 <= n1 || t1.is_zero() || t2.is_zero() {
+        return None;
+    }
+    Some((t2.as_secs_f64() / t1.as_secs_f64()).ln() / (n2 as f64 / n1 as f64).ln())
+}
+
+const SIZE_WIDTH: usize = 12;
+const COLUMN_WIDTH: usize = 20;
+
+impl fmt::Display for Scaling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} day {:02}, {} (seed {})",
+            self.year, self.day, self.unit, self.seed
+        )?;
+        write!(f, "{:<w$}{:<w$}", "size", "input", w = SIZE_WIDTH)?;
+        write!(f, "{:<w$}", "parse", w = COLUMN_WIDTH)?;
+        for part in self.parts.iter() {
+            write!(f, "{:<w$}", format!("part {}", part), w = COLUMN_WIDTH)?;
+        }
+        writeln!(f)?;
+
+        let mut previous: Option<(usize, Vec<Duration>)> = None;
+        for row in self.rows.iter() {
+            write!(
+                f,
+                "{:<w$}{:<w$}",
+                row.size,
+                format_bytes(row.bytes as u64),
+                w = SIZE_WIDTH
+            )?;
+            let medians: Vec<Duration> = match &row.outcome {
+                TimingOutcome::Timed { parse, parts } => std::iter::once(parse)
+                    .chain(parts.iter().map(|(_, stats)| stats))
+                    .map(|stats| stats.median)
+                    .collect(),
+                TimingOutcome::Failed(e) => {
+                    writeln!(f, "error: {}", e)?;
+                    continue;
+                }
+                TimingOutcome::MissingInput(path) => {
+                    writeln!(f, "missing input `{}`", path.display())?;
+                    continue;
+                }
+            };
+
+            for (i, &median) in medians.iter().enumerate() {
+                let growth = previous
+                    .as_ref()
+                    .and_then(|(size, times)| exponent((*size, times[i]), (row.size, median)));
+                let cell = match growth {
+                    Some(k) => format!("{} n^{:.2}", format_duration(median), k),
+                    None => format_duration(median),
+                };
+                write!(f, "{:<w$}", cell, w = COLUMN_WIDTH)?;
+            }
+            writeln!(f)?;
+            previous = Some((row.size, medians));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::run::{run_day, DayOutcome};
+    use crate::timing::Stats;
+
+    #[test]
+    fn test_exponent() {
+        let ms = Duration::from_millis;
+
+        let linear = exponent((100, ms(2)), (1000, ms(20))).unwrap();
+        assert!((linear - 1.0).abs() < 1e-9);
+        let quadratic = exponent((10, ms(1)), (100, ms(100))).unwrap();
+        assert!((quadratic - 2.0).abs() < 1e-9);
+        assert_eq!(None, exponent((100, ms(2)), (100, ms(3))));
+        assert_eq!(None, exponent((100, Duration::ZERO), (1000, ms(3))));
+    }
+
+    #[test]
+    fn test_table() {
+        let stats = |ms| Stats {
+            median: Duration::from_millis(ms),
+            min: Duration::from_millis(ms),
+            allocations: None,
+        };
+        let scaling = Scaling {
+            year: 2024,
+            day: 2,
+            unit: "levels per report",
+            seed: 1,
+            parts: vec![Part::One],
+            rows: vec![
+                ScaleRow {
+                    size: 10,
+                    bytes: 512,
+                    outcome: TimingOutcome::Timed {
+                        parse: stats(1),
+                        parts: vec![(Part::One, stats(2))],
+                    },
+                },
+                ScaleRow {
+                    size: 100,
+                    bytes: 5120,
+                    outcome: TimingOutcome::Timed {
+                        parse: stats(10),
+                        parts: vec![(Part::One, stats(200))],
+                    },
+                },
+                ScaleRow {
+                    size: 1000,
+                    bytes: 51200,
+                    outcome: TimingOutcome::Failed("panicked: overflow".to_string()),
+                },
+            ],
+        };
+
+        assert_eq!(
+            "2024 day 02, levels per report (seed 1)\n\
+             size        input       parse               part 1              \n\
+             10          512B        1.00ms              2.00ms              \n\
+             100         5.0KiB      10.0ms n^1.00       200ms n^2.00        \n\
+             1000        50.0KiB     error: panicked: overflow\n",
+            scaling.to_string()
+        );
+        assert!(scaling.failed());
+    }
+
+    #[test]
+    fn test_scale() {
+        let registry = registry();
+        let solution = registry.get(2024, 1).unwrap();
+        let generator = registry.generator(2024, 1).unwrap();
+
+        let scaling = scale(solution, generator, &[10, 100], 1, &Part::ALL, 1);
+        assert_eq!(
+            vec![10, 100],
+            scaling
+                .rows
+                .iter()
+                .map(|row| row.size)
+                .collect::<Vec<usize>>()
+        );
+        assert!(!scaling.failed());
+    }
+
+    #[test]
+    fn test_generated_inputs_are_valid() {
+        let registry = registry();
+
+        for generator in registry.generators() {
+            let solution = registry.get(generator.year, generator.day).unwrap();
+            for seed in 1..=3 {
+                let input = generator.generate(seed, 20);
+                let run = run_day(solution, &input, &Part::ALL);
+                assert!(
+                    matches!(&run.outcome, DayOutcome::Solved(parts)
+                        if parts.iter().all(|part| part.answer.is_ok())),
+                    "{} day {:02}, seed {}: {:?}",
+                    generator.year,
+                    generator.day,
+                    seed,
+                    run.outcome
+                );
+            }
+        }
+    }
+}
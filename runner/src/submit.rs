@@ -0,0 +1,485 @@
+//! Answer submission, with a local log of every outcome per year
+//!
+//! Outcomes are appended to the year's `submissions.log`, one tab separated
+//! line per submission: `day part answer outcome unix_time`. The log is used
+//! to refuse answers already known to be wrong before they reach the website.
+
+use crate::client::{Client, ClientError};
+use crate::year_dir;
+use aoc_utils::{Answer, Part};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error("cannot submit: {0}")]
+    NotSubmittable(String),
+    #[error("refusing to submit `{answer}`: {reason}")]
+    Refused { answer: String, reason: String },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("cannot read submissions log `{path}`: {source}")]
+    CannotReadLog { path: PathBuf, source: io::Error },
+    #[error("invalid submissions log `{path}` line {line}: {message}")]
+    InvalidLog {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    #[error("cannot write submissions log `{path}`: {source}")]
+    CannotWriteLog { path: PathBuf, source: io::Error },
+}
+
+/// Response of the website to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without any hint
+    Wrong,
+    /// The part was already solved, the answer was not checked
+    AlreadySolved,
+    RateLimited(Duration),
+    /// Response not recognized, holding its text
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Classify the response page of a submission
+    pub fn from_html(html: &str) -> Outcome {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited(parse_wait(&text).unwrap_or_default())
+        } else if text.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    /// Whether the submitted answer is known to be wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn log_token(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_string(),
+            Outcome::TooHigh => "too_high".to_string(),
+            Outcome::TooLow => "too_low".to_string(),
+            Outcome::Wrong => "wrong".to_string(),
+            Outcome::AlreadySolved => "already_solved".to_string(),
+            Outcome::RateLimited(wait) => format!("rate_limited:{}", wait.as_secs()),
+            Outcome::Unknown(_) => "unknown".to_string(),
+        }
+    }
+
+    fn from_log_token(token: &str) -> Result<Outcome, String> {
+        match token {
+            "correct" => Ok(Outcome::Correct),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "already_solved" => Ok(Outcome::AlreadySolved),
+            "unknown" => Ok(Outcome::Unknown(String::new())),
+            token => token
+                .strip_prefix("rate_limited:")
+                .and_then(|secs| secs.parse().ok())
+                .map(|secs| Outcome::RateLimited(Duration::from_secs(secs)))
+                .ok_or_else(|| format!("unknown outcome `{}`", token)),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` of a page, tags removed and whitespace collapsed
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Wait time of `You have 1m 30s left to wait`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let before = &text[..text.find(" left to wait")?];
+    let amount = &before[before.rfind("You have ")? + "You have ".len()..];
+
+    let mut secs = 0;
+    for part in amount.split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        secs += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.day,
+            self.part,
+            self.answer,
+            self.outcome.log_token(),
+            self.timestamp
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, got {}", fields.len()));
+        }
+
+        let part = fields[1]
+            .parse::<u8>()
+            .map_err(|e| e.to_string())
+            .and_then(Part::try_from)?;
+        Ok(Submission {
+            day: fields[0].parse().map_err(|_| "invalid day".to_string())?,
+            part,
+            answer: fields[2].to_string(),
+            outcome: Outcome::from_log_token(fields[3])?,
+            timestamp: fields[4]
+                .parse()
+                .map_err(|_| "invalid timestamp".to_string())?,
+        })
+    }
+}
+
+/// Submissions log of a year
+pub fn submissions_path(year: u16) -> PathBuf {
+    year_dir(year).join("submissions.log")
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Load a log, a missing file holding no submission
+    pub fn load(path: &Path) -> Result<SubmissionLog, SubmitError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(SubmitError::CannotReadLog {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        let submissions = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                line.parse().map_err(|message| SubmitError::InvalidLog {
+                    path: path.to_path_buf(),
+                    line: i + 1,
+                    message,
+                })
+            })
+            .collect::<Result<Vec<Submission>, SubmitError>>()?;
+        Ok(SubmissionLog { submissions })
+    }
+
+    /// Why the answer must not be submitted, if the log already tells it is wrong
+    pub fn refusal(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+        let value = answer.parse::<i128>().ok();
+
+        for submission in previous {
+            let bound = submission.answer.parse::<i128>().ok();
+            match (&submission.outcome, value, bound) {
+                (Outcome::Correct, _, _) => {
+                    return Some(format!("already solved with `{}`", submission.answer))
+                }
+                (outcome, _, _) if outcome.is_wrong() && submission.answer == answer => {
+                    return Some(format!("already submitted, {}", outcome))
+                }
+                (Outcome::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return Some(format!("`{}` is already known to be too high", bound))
+                }
+                (Outcome::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return Some(format!("`{}` is already known to be too low", bound))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Append a submission to the log file and to this log
+    pub fn record(&mut self, path: &Path, submission: Submission) -> Result<(), SubmitError> {
+        let cannot_write = |source| SubmitError::CannotWriteLog {
+            path: path.to_path_buf(),
+            source,
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(cannot_write)?;
+        writeln!(file, "{}", submission).map_err(cannot_write)?;
+
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// Submit an answer unless the log already rules it out, then log the outcome
+pub fn submit(
+    client: &Client,
+    log_path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome, SubmitError> {
+    let answer = match answer {
+        Answer::Unsolved => return Err(SubmitError::NotSubmittable("unsolved".to_string())),
+        Answer::Text(text) if text.contains('\n') => {
+            return Err(SubmitError::NotSubmittable(
+                "multi-line answers must be read and submitted by hand".to_string(),
+            ))
+        }
+        // the log is tab-separated
+        Answer::Text(text) if text.contains('\t') => {
+            return Err(SubmitError::NotSubmittable(
+                "answers cannot contain tabs".to_string(),
+            ))
+        }
+        answer => answer.to_string(),
+    };
+
+    let mut log = SubmissionLog::load(log_path)?;
+    if let Some(reason) = log.refusal(day, part, &answer) {
+        return Err(SubmitError::Refused { answer, reason });
+    }
+
+    let level = part.number().to_string();
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", &answer)],
+    )?;
+    let outcome = Outcome::from_html(&html);
+
+    log.record(
+        log_path,
+        Submission {
+            day,
+            part,
+            answer,
+            outcome: outcome.clone(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        },
+    )?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+    use std::env;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn submission(answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            day: 7,
+            part: Part::One,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_outcome_from_html() {
+        assert_eq!(
+            Outcome::Correct,
+            Outcome::from_html(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            ))
+        );
+        assert_eq!(
+            Outcome::TooHigh,
+            Outcome::from_html(&page(
+                "That's not the right answer; your answer is too high.  Please wait one minute."
+            ))
+        );
+        assert_eq!(
+            Outcome::TooLow,
+            Outcome::from_html(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Outcome::Wrong,
+            Outcome::from_html(&page("That's not the right answer.  If you're stuck, ..."))
+        );
+        assert_eq!(
+            Outcome::AlreadySolved,
+            Outcome::from_html(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Outcome::RateLimited(Duration::from_secs(272)),
+            Outcome::from_html(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait."))
+        );
+        assert_eq!(
+            Outcome::Unknown("Something else".to_string()),
+            Outcome::from_html(&page("Something <b>else</b>"))
+        );
+    }
+
+    #[test]
+    fn test_submission_log_line() {
+        let submission = submission("123", Outcome::RateLimited(Duration::from_secs(30)));
+
+        assert_eq!(
+            "7\t1\t123\trate_limited:30\t1700000000",
+            submission.to_string()
+        );
+        assert_eq!(Ok(submission.clone()), submission.to_string().parse());
+        assert!("7\t3\t123\twrong\t0".parse::<Submission>().is_err());
+    }
+
+    #[test]
+    fn test_refusal() {
+        let log = SubmissionLog {
+            submissions: vec![
+                submission("500", Outcome::TooHigh),
+                submission("100", Outcome::TooLow),
+                submission("abc", Outcome::Wrong),
+                submission("250", Outcome::RateLimited(Duration::from_secs(60))),
+            ],
+        };
+
+        assert!(log.refusal(7, Part::One, "500").is_some());
+        assert!(log.refusal(7, Part::One, "600").is_some());
+        assert!(log.refusal(7, Part::One, "99").is_some());
+        assert!(log.refusal(7, Part::One, "abc").is_some());
+        assert_eq!(None, log.refusal(7, Part::One, "250"));
+        assert_eq!(None, log.refusal(7, Part::Two, "600"));
+        assert_eq!(None, log.refusal(8, Part::One, "abc"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = FakeServer::start(vec![(
+            200,
+            page("That's not the right answer; your answer is too low."),
+        )]);
+        let client = Client::new(&server.url(), "secret");
+        let dir = env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let log_path = dir.join("submissions.log");
+
+        assert_eq!(
+            Outcome::TooLow,
+            submit(&client, &log_path, 2019, 7, Part::Two, &Answer::Int(42)).unwrap()
+        );
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2019/day/7/answer "));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+
+        // the outcome is logged, so the same answer is not sent again
+        let log = SubmissionLog::load(&log_path).unwrap();
+        assert_eq!(1, log.submissions.len());
+        assert_eq!(Outcome::TooLow, log.submissions[0].outcome);
+        assert!(matches!(
+            submit(&client, &log_path, 2019, 7, Part::Two, &Answer::Int(41)),
+            Err(SubmitError::Refused { .. })
+        ));
+        let tabbed = Answer::Text("4\t2".to_string());
+        assert!(matches!(
+            submit(&client, &log_path, 2019, 7, Part::Two, &tabbed),
+            Err(SubmitError::NotSubmittable(_))
+        ));
+        assert_eq!(1, server.requests().len());
+        assert_eq!(1, SubmissionLog::load(&log_path).unwrap().submissions.len());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,407 @@
+//! Timing of the parse phase and of each part, over repeated runs
+
+use crate::run::{read_input, DayOutcome, InputSource};
+use aoc_utils::alloc::measure;
+use aoc_utils::{AllocStats, DynSolution, Part};
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Median and minimum of repeated measurements of a phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    /// Allocations of the first run, when they are counted
+    pub allocations: Option<AllocStats>,
+}
+
+impl Stats {
+    /// Statistics of at least one sample
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+        Stats {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+            allocations: None,
+        }
+    }
+}
+
+/// Run `f`, timing it, and counting its allocations on the first run only
+fn sample<R>(first_run: bool, f: impl FnOnce() -> R) -> (R, Duration, Option<AllocStats>) {
+    let timed = || {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    };
+
+    if first_run {
+        let ((result, elapsed), allocations) = measure(timed);
+        (result, elapsed, allocations)
+    } else {
+        let (result, elapsed) = timed();
+        (result, elapsed, None)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimingOutcome {
+    MissingInput(PathBuf),
+    /// The input could not be read or parsed, or a part failed
+    Failed(String),
+    Timed {
+        parse: Stats,
+        parts: Vec<(Part, Stats)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTiming {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: TimingOutcome,
+}
+
+impl DayTiming {
+    /// Median time of parsing and solving every part
+    pub fn total(&self) -> Option<Duration> {
+        match &self.outcome {
+            TimingOutcome::Timed { parse, parts } => Some(
+                parse.median
+                    + parts
+                        .iter()
+                        .map(|(_, stats)| stats.median)
+                        .sum::<Duration>(),
+            ),
+            _ => None,
+        }
+    }
+
+    pub fn exceeds(&self, budget: Duration) -> bool {
+        self.total().is_some_and(|total| total > budget)
+    }
+}
+
+/// Parse the input and solve the parts `repeats` times, measuring each phase
+pub fn time_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    repeats: usize,
+) -> TimingOutcome {
+    let repeats = repeats.max(1);
+    let mut parse_samples = Vec::with_capacity(repeats);
+    let mut part_samples = vec![Vec::with_capacity(repeats); parts.len()];
+    let mut parse_allocations = None;
+    let mut part_allocations = vec![None; parts.len()];
+
+    for run in 0..repeats {
+        let (parsed, elapsed, allocations) = sample(run == 0, || solution.parse(input));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => return TimingOutcome::Failed(format!("cannot parse input: {}", e)),
+        };
+        parse_samples.push(elapsed);
+        parse_allocations = parse_allocations.or(allocations);
+
+        for (i, &part) in parts.iter().enumerate() {
+            let (answer, elapsed, allocations) =
+                sample(run == 0, || solution.solve(part, parsed.as_ref()));
+            if let Err(e) = answer {
+                return TimingOutcome::Failed(format!("part {}: {}", part, e));
+            }
+            part_samples[i].push(elapsed);
+            part_allocations[i] = part_allocations[i].or(allocations);
+        }
+    }
+
+    TimingOutcome::Timed {
+        parse: Stats {
+            allocations: parse_allocations,
+            ..Stats::new(&parse_samples)
+        },
+        parts: parts
+            .iter()
+            .zip(part_samples.iter().zip(part_allocations))
+            .map(|(&part, (samples, allocations))| {
+                (
+                    part,
+                    Stats {
+                        allocations,
+                        ..Stats::new(samples)
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Time every selected solution on its input
+pub fn time(
+    solutions: &[&dyn DynSolution],
+    source: &InputSource,
+    parts: &[Part],
+    repeats: usize,
+) -> Vec<DayTiming> {
+    solutions
+        .iter()
+        .map(|solution| {
+            let outcome = match read_input(source, solution.year(), solution.day()) {
+                Ok(input) => time_day(*solution, &input, parts, repeats),
+                Err(DayOutcome::MissingInput(path)) => TimingOutcome::MissingInput(path),
+                Err(DayOutcome::Failed(e)) => TimingOutcome::Failed(e),
+                Err(DayOutcome::Solved(_)) => unreachable!("reading an input solves nothing"),
+            };
+            DayTiming {
+                year: solution.year(),
+                day: solution.day(),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Human readable duration, with 3 significant digits
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+
+    match value {
+        v if v < 10.0 => format!("{:.2}{}", v, unit),
+        v if v < 100.0 => format!("{:.1}{}", v, unit),
+        v => format!("{:.0}{}", v, unit),
+    }
+}
+
+/// Human readable size, in binary units
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
+
+/// Allocation count, bytes allocated and peak live bytes
+fn format_allocations(allocations: &AllocStats) -> String {
+    format!(
+        "{} / {} / {}",
+        allocations.allocations,
+        format_bytes(allocations.bytes),
+        format_bytes(allocations.peak as u64)
+    )
+}
+
+/// Parse durations such as `500ms`, `1.5s`, `250us` or `800ns`
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| format!("missing unit in `{}` (ns, us, ms or s)", s))?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{}`", s))?;
+
+    let seconds = match unit {
+        "ns" => value / 1e9,
+        "us" | "µs" => value / 1e6,
+        "ms" => value / 1e3,
+        "s" => value,
+        _ => return Err(format!("unknown unit `{}` (ns, us, ms or s)", unit)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("duration `{}` is too long", s))
+}
+
+/// Timings of a year as a table, with a total row
+pub struct YearTable<'a> {
+    pub year: u16,
+    pub timings: &'a [DayTiming],
+    pub parts: &'a [Part],
+    pub budget: Option<Duration>,
+}
+
+const COLUMN_WIDTH: usize = 24;
+
+impl fmt::Display for YearTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<8}{:<w$}", self.year, "parse", w = COLUMN_WIDTH)?;
+        for part in self.parts {
+            write!(f, "{:<w$}", format!("part {}", part), w = COLUMN_WIDTH)?;
+        }
+        writeln!(f, "total")?;
+
+        for timing in self.timings.iter().filter(|t| t.year == self.year) {
+            write!(f, "day {:02}  ", timing.day)?;
+            match &timing.outcome {
+                TimingOutcome::MissingInput(path) => {
+                    writeln!(f, "missing input `{}`", path.display())?
+                }
+                TimingOutcome::Failed(e) => writeln!(f, "error: {}", e)?,
+                TimingOutcome::Timed { parse, parts } => {
+                    let phases: Vec<&Stats> = std::iter::once(parse)
+                        .chain(parts.iter().map(|(_, s)| s))
+                        .collect();
+                    for stats in phases.iter() {
+                        let cell = format!(
+                            "{} ({})",
+                            format_duration(stats.median),
+                            format_duration(stats.min)
+                        );
+                        write!(f, "{:<w$}", cell, w = COLUMN_WIDTH)?;
+                    }
+                    let total = timing.total().unwrap_or_default();
+                    write!(f, "{}", format_duration(total))?;
+                    match self.budget {
+                        Some(budget) if total > budget => {
+                            writeln!(f, "  OVER BUDGET ({})", format_duration(budget))?
+                        }
+                        _ => writeln!(f)?,
+                    }
+
+                    if phases.iter().any(|stats| stats.allocations.is_some()) {
+                        write!(f, "{:8}", "")?;
+                        for stats in phases.iter() {
+                            let cell = stats
+                                .allocations
+                                .as_ref()
+                                .map(format_allocations)
+                                .unwrap_or_default();
+                            write!(f, "{:<w$}", cell, w = COLUMN_WIDTH)?;
+                        }
+                        writeln!(f, "allocations / bytes / peak")?;
+                    }
+                }
+            }
+        }
+
+        let total: Duration = self
+            .timings
+            .iter()
+            .filter(|t| t.year == self.year)
+            .filter_map(|t| t.total())
+            .sum();
+        writeln!(
+            f,
+            "{:<w$}{}",
+            "total",
+            format_duration(total),
+            w = 8 + COLUMN_WIDTH * (self.parts.len() + 1)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Stats {
+                median: ms(3),
+                min: ms(1),
+                allocations: None,
+            },
+            Stats::new(&[ms(5), ms(1), ms(3), ms(4), ms(2)])
+        );
+        assert_eq!(
+            Stats {
+                median: ms(7),
+                min: ms(7),
+                allocations: None,
+            },
+            Stats::new(&[ms(7)])
+        );
+    }
+
+    #[test]
+    fn test_time_day() {
+        let registry = registry();
+        let solution = registry.get(2024, 1).unwrap();
+
+        match time_day(solution, "3   4\n4   3\n", &Part::ALL, 3) {
+            TimingOutcome::Timed { parts, .. } => {
+                assert_eq!(
+                    vec![Part::One, Part::Two],
+                    parts.iter().map(|(p, _)| *p).collect::<Vec<Part>>()
+                );
+            }
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+        assert!(matches!(
+            time_day(solution, "3   x\n", &Part::ALL, 3),
+            TimingOutcome::Failed(_)
+        ));
+    }
+
+    #[test]
+    fn test_budget() {
+        let stats = |ms| Stats {
+            median: Duration::from_millis(ms),
+            min: Duration::from_millis(ms),
+            allocations: None,
+        };
+        let timing = DayTiming {
+            year: 2019,
+            day: 7,
+            outcome: TimingOutcome::Timed {
+                parse: stats(1),
+                parts: vec![(Part::One, stats(2)), (Part::Two, stats(3))],
+            },
+        };
+
+        assert_eq!(Some(Duration::from_millis(6)), timing.total());
+        assert!(timing.exceeds(Duration::from_millis(5)));
+        assert!(!timing.exceeds(Duration::from_millis(6)));
+    }
+
+    #[test]
+    fn test_durations() {
+        assert_eq!("850ns", format_duration(Duration::from_nanos(850)));
+        assert_eq!("12.3µs", format_duration(Duration::from_nanos(12_345)));
+        assert_eq!("1.50s", format_duration(Duration::from_millis(1500)));
+
+        assert_eq!(Ok(Duration::from_millis(500)), parse_duration("500ms"));
+        assert_eq!(Ok(Duration::from_micros(1500)), parse_duration("1.5ms"));
+        assert_eq!(Ok(Duration::from_micros(250)), parse_duration("250us"));
+        assert!(parse_duration("500").is_err());
+        assert!(parse_duration("5m").is_err());
+        assert!(parse_duration("99999999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn test_allocations() {
+        assert_eq!("512B", format_bytes(512));
+        assert_eq!("1.5KiB", format_bytes(1536));
+        assert_eq!("3.0MiB", format_bytes(3 * 1024 * 1024));
+        assert_eq!(
+            "12 / 4.0KiB / 2.0KiB",
+            format_allocations(&AllocStats {
+                allocations: 12,
+                bytes: 4096,
+                peak: 2048,
+            })
+        );
+    }
+}
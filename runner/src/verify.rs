@@ -0,0 +1,186 @@
+//! Regression check of solutions against the accepted answers of each year
+
+use crate::run::{run, DayOutcome, InputSource};
+use aoc_utils::answers::AnswersError;
+use aoc_utils::{answers_path, Answer, DynSolution, KnownAnswers, Part, Verdict};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// The solution errored, or the input could not be parsed
+    Error(String),
+    /// Nothing to compare: no known answer, or no local input
+    Missing(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartCheck {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+impl PartCheck {
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+impl fmt::Display for PartCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {:02} part {}: ", self.year, self.day, self.part)?;
+        match &self.status {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL (expected `{}`, got `{}`)", expected, actual)
+            }
+            Status::Error(e) => write!(f, "ERROR ({})", e),
+            Status::Missing(reason) => write!(f, "missing ({})", reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl Summary {
+    pub fn new(checks: &[PartCheck]) -> Summary {
+        checks.iter().fold(Summary::default(), |mut s, c| {
+            match c.status {
+                Status::Pass => s.passed += 1,
+                Status::Fail { .. } | Status::Error(_) => s.failed += 1,
+                Status::Missing(_) => s.missing += 1,
+            }
+            s
+        })
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        )
+    }
+}
+
+fn check_answer(answers: &KnownAnswers, day: u8, part: Part, answer: &Answer) -> Status {
+    match answers.check(day, part, answer) {
+        Verdict::Pass => Status::Pass,
+        Verdict::Fail { expected } => Status::Fail {
+            expected,
+            actual: answer.to_string(),
+        },
+        Verdict::Missing if answer.is_solved() => {
+            Status::Missing(format!("no known answer, got `{}`", answer))
+        }
+        Verdict::Missing => Status::Missing("unsolved".to_string()),
+    }
+}
+
+/// Run the solutions on their local input and compare with the known answers
+pub fn verify(solutions: &[&dyn DynSolution]) -> Result<Vec<PartCheck>, AnswersError> {
+    let mut answers: BTreeMap<u16, KnownAnswers> = BTreeMap::new();
+    for solution in solutions {
+        if let Entry::Vacant(entry) = answers.entry(solution.year()) {
+            entry.insert(KnownAnswers::load(&answers_path(solution.year()))?);
+        }
+    }
+
+    let mut checks = Vec::new();
+    for day_run in run(solutions, &InputSource::Default, &Part::ALL) {
+        let known = &answers[&day_run.year];
+        let check = |part: Part, status: Status| PartCheck {
+            year: day_run.year,
+            day: day_run.day,
+            part,
+            status,
+        };
+
+        match &day_run.outcome {
+            DayOutcome::MissingInput(_) => checks.extend(
+                Part::ALL
+                    .iter()
+                    .map(|&part| check(part, Status::Missing("no input".to_string()))),
+            ),
+            DayOutcome::Failed(e) => checks.extend(
+                Part::ALL
+                    .iter()
+                    .map(|&part| check(part, Status::Error(e.clone()))),
+            ),
+            DayOutcome::Solved(parts) => checks.extend(parts.iter().map(|p| {
+                check(
+                    p.part,
+                    match &p.answer {
+                        Ok(answer) => check_answer(known, day_run.day, p.part, answer),
+                        Err(e) => Status::Error(e.clone()),
+                    },
+                )
+            })),
+        }
+    }
+
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_answer() {
+        let answers = KnownAnswers::from_toml("[day01]\npart1 = 11\n").unwrap();
+
+        assert_eq!(
+            Status::Pass,
+            check_answer(&answers, 1, Part::One, &Answer::Int(11))
+        );
+        assert_eq!(
+            Status::Fail {
+                expected: "11".to_string(),
+                actual: "unsolved".to_string()
+            },
+            check_answer(&answers, 1, Part::One, &Answer::Unsolved)
+        );
+        assert_eq!(
+            Status::Missing("unsolved".to_string()),
+            check_answer(&answers, 1, Part::Two, &Answer::Unsolved)
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let check = |status| PartCheck {
+            year: 2024,
+            day: 1,
+            part: Part::One,
+            status,
+        };
+        let checks = vec![
+            check(Status::Pass),
+            check(Status::Error("boom".to_string())),
+            check(Status::Missing("no input".to_string())),
+            check(Status::Missing("unsolved".to_string())),
+        ];
+
+        assert_eq!(
+            Summary {
+                passed: 1,
+                failed: 1,
+                missing: 2
+            },
+            Summary::new(&checks)
+        );
+    }
+}
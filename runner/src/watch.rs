@@ -0,0 +1,381 @@
+//! Re-running a day whenever its sources, input or examples change
+//!
+//! Changes are detected with the file notifications of the operating system.
+//! As the solutions are compiled into the runner, each change rebuilds it with
+//! cargo, then runs the examples and the real input through `aoc run`.
+
+use crate::{workspace_root, year_dir};
+use aoc_utils::crypt::input_exists;
+use aoc_utils::fixture::example_numbers;
+use aoc_utils::{example_path, input_dir, input_path, AocInputError, Fixture};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Time to wait for the other events of a burst, e.g. an editor saving a file
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Error, Debug)]
+pub enum WatchError {
+    #[error("cannot watch files: {0}")]
+    Notify(#[from] notify::Error),
+    #[error("cannot run cargo: {0}")]
+    CannotRunCargo(io::Error),
+    #[error("unexpected output of `aoc run`: {0}")]
+    InvalidOutput(String),
+    #[error(transparent)]
+    Input(#[from] AocInputError),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Input a day is run on, the examples coming before the real input
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WatchedInput {
+    Example(usize),
+    Input,
+}
+
+impl fmt::Display for WatchedInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WatchedInput::Example(n) => write!(f, "example {}", n),
+            WatchedInput::Input => write!(f, "input"),
+        }
+    }
+}
+
+/// Answer, or error, of each input and part
+pub type Answers = BTreeMap<(WatchedInput, u8), String>;
+
+/// Whether a change to `path` affects the day
+///
+/// These are the day's module and binary, the year's `lib.rs`, the day's
+/// input and its `dayXX_N.txt` examples.
+pub fn is_relevant(path: &Path, day: u8) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let prefix = format!("day{:02}", day);
+
+    name == "lib.rs"
+        || name == format!("{}.rs", prefix)
+        || name == format!("{}.txt", prefix)
+        || name
+            .strip_prefix(&format!("{}_", prefix))
+            .and_then(|rest| rest.strip_suffix(".txt"))
+            .is_some_and(|n| n.parse::<usize>().is_ok())
+}
+
+/// Examples of a day in `examples_dir`, ordered by number
+pub fn examples(examples_dir: &Path, day: u8) -> Vec<(usize, PathBuf)> {
+    example_numbers(examples_dir, day)
+        .into_iter()
+        .map(|n| (n, examples_dir.join(format!("day{:02}_{}.txt", day, n))))
+        .collect()
+}
+
+/// Lines describing the answers, compared with the previous ones
+pub fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|((input, part), answer)| {
+            let line = format!("{} part {}: {}", input, part, answer);
+            match previous.get(&(input.clone(), *part)) {
+                None => line,
+                Some(previous) if previous == answer => format!("{} (unchanged)", line),
+                Some(previous) => format!("{} (was {})", line, previous),
+            }
+        })
+        .collect()
+}
+
+/// Answers from the JSON report of `aoc run`
+fn parse_answers(input: &WatchedInput, json: &str) -> Result<Answers, WatchError> {
+    let records: Vec<Value> =
+        serde_json::from_str(json).map_err(|e| WatchError::InvalidOutput(e.to_string()))?;
+
+    records
+        .iter()
+        .map(|record| {
+            let part = record["part"]
+                .as_u64()
+                .ok_or_else(|| WatchError::InvalidOutput(record.to_string()))?;
+            let status = record["status"].as_str().unwrap_or_default();
+            let answer = match (&record["answer"], &record["message"]) {
+                (Value::String(text), _) => text.clone(),
+                (Value::Null, Value::String(message)) => format!("{}: {}", status, message),
+                (Value::Null, _) => status.to_string(),
+                (answer, _) => answer.to_string(),
+            };
+            Ok(((input.clone(), part as u8), answer))
+        })
+        .collect()
+}
+
+/// Re-runs of a day, with cargo invoked from the workspace root
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    /// Build and run in release mode
+    pub release: bool,
+}
+
+impl Watch {
+    fn cargo(&self, command: &str) -> Command {
+        let mut cargo = Command::new(env!("CARGO"));
+        cargo
+            .current_dir(workspace_root())
+            .args([command, "--quiet", "--package", "aoc"]);
+        if self.release {
+            cargo.arg("--release");
+        }
+        cargo
+    }
+
+    fn examples_dir(&self) -> PathBuf {
+        example_path(self.year, self.day, 1)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    }
+
+    /// Rebuild the runner, cargo reporting compilation errors, if any
+    pub fn build(&self) -> Result<bool, WatchError> {
+        let status = self
+            .cargo("build")
+            .status()
+            .map_err(WatchError::CannotRunCargo)?;
+        Ok(status.success())
+    }
+
+    /// Answers on every example, then on the real input when there is one
+    pub fn answers(&self) -> Result<Answers, WatchError> {
+        let mut inputs: Vec<(WatchedInput, PathBuf)> = examples(&self.examples_dir(), self.day)
+            .into_iter()
+            .map(|(n, path)| (WatchedInput::Example(n), path))
+            .collect();
+        let path = input_path(self.year, self.day);
+        if input_exists(&path) {
+            inputs.push((WatchedInput::Input, path));
+        }
+
+        let mut answers = Answers::new();
+        for (input, path) in inputs {
+            let output = match input {
+                // piped without the header of the fixture, its parameters passed as options
+                WatchedInput::Example(_) => self.run_on_stdin(&Fixture::load(&path)?)?,
+                WatchedInput::Input => self
+                    .aoc_run()
+                    .arg("--input")
+                    .arg(&path)
+                    .output()
+                    .map_err(WatchError::CannotRunCargo)?,
+            };
+            answers.extend(parse_answers(
+                &input,
+                &String::from_utf8_lossy(&output.stdout),
+            )?);
+        }
+        Ok(answers)
+    }
+
+    /// `aoc run` of the day, reporting as JSON
+    fn aoc_run(&self) -> Command {
+        let mut command = self.cargo("run");
+        command
+            .args(["--", "run", &self.year.to_string(), &self.day.to_string()])
+            .args(["--format", "json"])
+            .stderr(Stdio::inherit());
+        command
+    }
+
+    fn run_on_stdin(&self, fixture: &Fixture) -> Result<Output, WatchError> {
+        let mut command = self.aoc_run();
+        for (name, value) in fixture.params.iter() {
+            command.arg("--param").arg(format!("{}={}", name, value));
+        }
+        let mut child = command
+            .args(["--input", "-"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(WatchError::CannotRunCargo)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // the run may fail before reading its input, closing the pipe
+            let _ = stdin.write_all(fixture.input.as_bytes());
+        }
+        child.wait_with_output().map_err(WatchError::CannotRunCargo)
+    }
+
+    /// Rebuild and re-run the day on every relevant change, until interrupted
+    pub fn run(&self) -> Result<(), WatchError> {
+        let (sender, receiver) = mpsc::channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(sender)?;
+
+        let year_dir = year_dir(self.year);
+        for dir in [
+            year_dir.join("src"),
+            year_dir.join("src").join("bin"),
+            input_dir(self.year),
+            self.examples_dir(),
+        ] {
+            if dir.is_dir() {
+                watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+            }
+        }
+
+        let mut previous = Answers::new();
+        let mut changed: Vec<PathBuf> = vec![];
+        loop {
+            writeln!(io::stdout(), "--- {} day {:02} ---", self.year, self.day)?;
+            for path in changed.iter() {
+                writeln!(io::stdout(), "changed `{}`", display_path(path))?;
+            }
+            if self.build()? {
+                match self.answers() {
+                    Ok(answers) => {
+                        for line in diff(&previous, &answers) {
+                            writeln!(io::stdout(), "{}", line)?;
+                        }
+                        previous = answers;
+                    }
+                    Err(e @ (WatchError::InvalidOutput(_) | WatchError::Input(_))) => {
+                        writeln!(io::stdout(), "error: {}", e)?
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            changed = self.wait_for_change(&receiver)?;
+        }
+    }
+
+    /// Paths changed by the next burst of relevant events
+    fn wait_for_change(
+        &self,
+        receiver: &mpsc::Receiver<notify::Result<Event>>,
+    ) -> Result<Vec<PathBuf>, WatchError> {
+        let mut changed = vec![];
+        while changed.is_empty() {
+            let first = receiver
+                .recv()
+                .map_err(|_| notify::Error::generic("file watcher stopped"))?;
+            let burst =
+                iter::once(first).chain(iter::from_fn(|| receiver.recv_timeout(DEBOUNCE).ok()));
+            for event in burst {
+                let event = event?;
+                if matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    changed.extend(event.paths.into_iter().filter(|p| is_relevant(p, self.day)));
+                }
+            }
+        }
+
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    path.strip_prefix(workspace_root())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_is_relevant() {
+        assert!(is_relevant(Path::new("years/2024/src/day03.rs"), 3));
+        assert!(is_relevant(Path::new("years/2024/src/bin/day03.rs"), 3));
+        assert!(is_relevant(Path::new("years/2024/src/lib.rs"), 3));
+        assert!(is_relevant(Path::new("years/2024/inputs/day03.txt"), 3));
+        assert!(is_relevant(Path::new("inputs/examples/day03_2.txt"), 3));
+
+        assert!(!is_relevant(Path::new("years/2024/src/day13.rs"), 3));
+        assert!(!is_relevant(Path::new("years/2024/src/day03.md"), 3));
+        assert!(!is_relevant(
+            Path::new("inputs/examples/day03_2.txt.swp"),
+            3
+        ));
+        assert!(!is_relevant(Path::new("inputs/day03.txt.part"), 3));
+    }
+
+    #[test]
+    fn test_examples_are_ordered_by_number() {
+        let dir = env::temp_dir().join(format!("aoc_watch_examples_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["day03_10.txt", "day03_2.txt", "day03_1.txt", "day04_1.txt"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let numbers: Vec<usize> = examples(&dir, 3).into_iter().map(|(n, _)| n).collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec![1, 2, 10], numbers);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let json = r#"[
+            {"year": 2024, "day": 3, "part": 1, "answer": 161, "answer_type": "uint", "duration_ns": 10, "status": "ok"},
+            {"year": 2024, "day": 3, "part": 2, "answer": null, "answer_type": null, "duration_ns": 10, "status": "error", "message": "no match"}
+        ]"#;
+
+        let answers = parse_answers(&WatchedInput::Example(1), json).unwrap();
+        assert_eq!(
+            Some(&"161".to_string()),
+            answers.get(&(WatchedInput::Example(1), 1))
+        );
+        assert_eq!(
+            Some(&"error: no match".to_string()),
+            answers.get(&(WatchedInput::Example(1), 2))
+        );
+        assert!(parse_answers(&WatchedInput::Input, "part 1: 161").is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let answers = |values: &[(WatchedInput, u8, &str)]| -> Answers {
+            values
+                .iter()
+                .map(|(input, part, answer)| ((input.clone(), *part), answer.to_string()))
+                .collect()
+        };
+        let previous = answers(&[
+            (WatchedInput::Example(1), 1, "161"),
+            (WatchedInput::Input, 1, "150"),
+        ]);
+        let current = answers(&[
+            (WatchedInput::Example(1), 1, "161"),
+            (WatchedInput::Example(1), 2, "48"),
+            (WatchedInput::Input, 1, "170"),
+        ]);
+
+        assert_eq!(
+            vec![
+                "example 1 part 1: 161 (unchanged)",
+                "example 1 part 2: 48",
+                "input part 1: 170 (was 150)",
+            ],
+            diff(&previous, &current)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Allocation accounting, enabled by the `count-allocations` feature
+//!
+//! With the feature, [`CountingAllocator`] becomes the global allocator of
+//! every binary linking `aoc_utils`, and [`measure`] reports the allocations
+//! made while running a closure. Without it, normal builds keep the system
+//! allocator and [`measure`] reports nothing.
+//!
+//! Counters are process-wide: allocations of other threads made during a
+//! measurement are included, so measure with a single thread at work.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Allocations made while running a closure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, reallocations included
+    pub allocations: u64,
+    /// Total of the bytes allocated
+    pub bytes: u64,
+    /// Highest number of bytes live at once, above those live at the start
+    pub peak: usize,
+}
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator keeping count of allocations and live bytes
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Whether allocations are counted, i.e. the `count-allocations` feature is enabled
+pub fn is_counting() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+/// Run `f`, with its allocations when they are counted
+///
+/// Measurements must not be nested, as each one resets the peak.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    if !is_counting() {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let kept = vec![0u8; 4096];
+            drop(vec![0u8; 1024]);
+            kept.len()
+        });
+
+        assert_eq!(4096, len);
+        match stats {
+            Some(stats) => {
+                assert!(stats.allocations >= 2);
+                assert!(stats.bytes >= 5120);
+                assert!(stats.peak >= 4096);
+            }
+            None => assert!(!is_counting()),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Accepted answers of a year, stored in its `answers.toml`
+//!
+//! ```toml
+//! [day07]
+//! part1 = 43210
+//! part2 = 139629729
+//! ```
+//!
+//! Text answers are stored as strings.
+
+use crate::{Answer, Part};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("cannot read `{path}`: {source}")]
+    CannotRead { path: PathBuf, source: io::Error },
+    #[error("invalid answers file `{path}`: {message}")]
+    InvalidFile { path: PathBuf, message: String },
+}
+
+/// Result of checking an answer against the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No accepted answer is known for this part
+    Missing,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl KnownAnswers {
+    /// Load answers from a file, a missing file holding no answers
+    pub fn load(path: &Path) -> Result<KnownAnswers, AnswersError> {
+        if !path.exists() {
+            return Ok(KnownAnswers::default());
+        }
+        let content = fs::read_to_string(path).map_err(|source| AnswersError::CannotRead {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        KnownAnswers::from_toml(&content).map_err(|message| AnswersError::InvalidFile {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    pub fn from_toml(content: &str) -> Result<KnownAnswers, String> {
+        let table = content.parse::<toml::Table>().map_err(|e| e.to_string())?;
+        let mut answers = BTreeMap::new();
+
+        for (day_key, parts) in table.iter() {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| format!("unknown section `[{}]`, expected `[dayXX]`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` must be a table", day_key))?;
+
+            for (part_key, value) in parts.iter() {
+                let part = match part_key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("unknown key `{}.{}`", day_key, part_key)),
+                };
+                let answer = match value {
+                    toml::Value::Integer(v) => v.to_string(),
+                    toml::Value::String(v) => v.clone(),
+                    _ => {
+                        return Err(format!(
+                            "`{}.{}` must be an integer or a string",
+                            day_key, part_key
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Days with at least one known answer, in order
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.answers.keys().map(|&(day, _)| day).collect();
+        days.dedup();
+        days
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if answer.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        let answers = KnownAnswers::from_toml(
+            "[day07]\npart1 = 43210\n\n[day08]\npart1 = 1\npart2 = \"\"\"\n#..\n.#.\n\"\"\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(Some("43210"), answers.get(7, Part::One));
+        assert_eq!(None, answers.get(7, Part::Two));
+        assert_eq!(Some("#..\n.#.\n"), answers.get(8, Part::Two));
+        assert_eq!(vec![7, 8], answers.days());
+    }
+
+    #[test]
+    fn test_from_toml_invalid() {
+        assert!(KnownAnswers::from_toml("[seven]\npart1 = 1\n").is_err());
+        assert!(KnownAnswers::from_toml("[day07]\npart3 = 1\n").is_err());
+        assert!(KnownAnswers::from_toml("[day07]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers =
+            KnownAnswers::from_toml("[day01]\npart1 = 11\npart2 = \"\\n#.\\n\"\n").unwrap();
+
+        assert_eq!(
+            Verdict::Pass,
+            answers.check(1, Part::One, &Answer::UInt(11))
+        );
+        assert_eq!(
+            Verdict::Fail {
+                expected: "11".to_string()
+            },
+            answers.check(1, Part::One, &Answer::Int(-11))
+        );
+        assert_eq!(
+            Verdict::Pass,
+            answers.check(1, Part::Two, &Answer::Text("#.".to_string()))
+        );
+        assert_eq!(
+            Verdict::Missing,
+            answers.check(2, Part::One, &Answer::Int(1))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Criterion benchmarks of every solution of a registry
+//!
+//! Each year crate has a single bench target handing its registry to
+//! [`bench_registry`], so a registered day is benchmarked without further
+//! code. Enabled by the `bench` feature, as a dev-dependency of year crates.
+
+use crate::crypt::read_input_file;
+use crate::paths::{example_path, input_path};
+use crate::{DynSolution, Fixture, Part, Registry};
+use criterion::{black_box, Criterion};
+
+/// Input a solution is benchmarked on: the real input, or else its first example
+fn bench_input(solution: &dyn DynSolution) -> Option<(Fixture, &'static str)> {
+    let (year, day) = (solution.year(), solution.day());
+    if let Ok(input) = read_input_file(&input_path(year, day)) {
+        let input = Fixture {
+            input,
+            ..Fixture::default()
+        };
+        return Some((input, "input"));
+    }
+    Fixture::load(&example_path(year, day, 1))
+        .ok()
+        .map(|example| (example, "example"))
+}
+
+/// Bench the parsing and each part of one solution, as group `YYYY_dayXX`
+///
+/// Days without input nor example are skipped, as are the phases failing on
+/// the benchmarked input.
+pub fn bench_solution(c: &mut Criterion, solution: &dyn DynSolution) {
+    let name = format!("{}_day{:02}", solution.year(), solution.day());
+    let Some((Fixture { input, params, .. }, source)) = bench_input(solution) else {
+        eprintln!("{}: skipped, no input nor example", name);
+        return;
+    };
+    let parsed = match solution.parse_with(&input, &params) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}: skipped, cannot parse the {}: {}", name, source, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(name.as_str());
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse_with(black_box(&input), &params))
+    });
+    for part in Part::ALL {
+        match solution.solve(part, parsed.as_ref()) {
+            Ok(answer) if answer.is_solved() => {
+                group.bench_function(format!("part{}", part), |b| {
+                    b.iter(|| solution.solve(part, black_box(parsed.as_ref())))
+                });
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!(
+                "{}: part {} skipped, fails on the {}: {}",
+                name, part, source, e
+            ),
+        }
+    }
+    group.finish();
+}
+
+/// Bench every solution of the registry, in year and day order
+pub fn bench_registry(c: &mut Criterion, registry: &Registry) {
+    for solution in registry.iter() {
+        bench_solution(c, solution);
+    }
+}
@@ -0,0 +1,334 @@
+//! Encrypted puzzle inputs, to version them without publishing them
+//!
+//! `aoc encrypt` writes every `inputs/dayXX.txt` to `inputs/dayXX.txt.enc`,
+//! encrypted with ChaCha20-Poly1305 under a key shared out of band. When a
+//! plaintext input is absent, [`read_input_file`] decrypts its `.enc` file in
+//! memory, so solutions, tests and the runner read either transparently.
+//!
+//! The key is 32 bytes, written in hexadecimal to `.aoc_key` at the workspace
+//! root, or to the file named by `AOC_KEY_FILE`. An encrypted file holds a
+//! version line, a random nonce, then the ciphertext and its tag. The name of
+//! the plaintext file is authenticated with it, so inputs cannot be swapped.
+
+use crate::input::read_file;
+use crate::paths::workspace_dir;
+use crate::AocInputError;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Environment variable overriding the key file path
+pub const KEY_FILE_VAR: &str = "AOC_KEY_FILE";
+
+/// Start of every encrypted file, identifying its format
+const VERSION: &[u8] = b"aoc-enc-v1\n";
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+#[derive(Error, Debug)]
+pub enum CryptError {
+    #[error("no key file `{}` (set `{KEY_FILE_VAR}` to use another one)", .0.display())]
+    NoKey(PathBuf),
+    #[error("cannot read key file `{path}`: {source}")]
+    CannotReadKey { path: PathBuf, source: io::Error },
+    #[error("invalid key file `{}`: expected {} hexadecimal digits", .0.display(), KEY_LEN * 2)]
+    InvalidKey(PathBuf),
+    #[error("cannot read `{path}`: {source}")]
+    CannotRead { path: PathBuf, source: io::Error },
+    #[error("cannot write `{path}`: {source}")]
+    CannotWrite { path: PathBuf, source: io::Error },
+    #[error("`{}` is not an encrypted input", .0.display())]
+    NotEncrypted(PathBuf),
+    #[error("cannot decrypt `{}`: wrong key, or altered file", .0.display())]
+    CannotDecrypt(PathBuf),
+}
+
+/// Key file used when `AOC_KEY_FILE` is not set
+pub fn default_key_file() -> PathBuf {
+    workspace_dir().join(".aoc_key")
+}
+
+/// Key file from `AOC_KEY_FILE`, or else the default one
+pub fn key_file() -> PathBuf {
+    env::var_os(KEY_FILE_VAR)
+        .filter(|path| !path.is_empty())
+        .map_or_else(default_key_file, PathBuf::from)
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; KEY_LEN]);
+
+impl Key {
+    /// Random key, from the operating system
+    pub fn generate() -> Key {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Option<Key> {
+        let hex = hex.trim();
+        if hex.len() != KEY_LEN * 2 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let mut key = [0; KEY_LEN];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
+        }
+        Some(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub fn load(path: &Path) -> Result<Key, CryptError> {
+        let hex = match fs::read_to_string(path) {
+            Ok(hex) => hex,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(CryptError::NoKey(path.to_path_buf()))
+            }
+            Err(source) => {
+                return Err(CryptError::CannotReadKey {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        Key::from_hex(&hex).ok_or_else(|| CryptError::InvalidKey(path.to_path_buf()))
+    }
+
+    /// Key of the key file, see [`key_file`]
+    pub fn from_env() -> Result<Key, CryptError> {
+        Key::load(&key_file())
+    }
+
+    /// Write the key to a new file, never replacing an existing key
+    pub fn save(&self, path: &Path) -> Result<(), CryptError> {
+        let cannot_write = |source| CryptError::CannotWrite {
+            path: path.to_path_buf(),
+            source,
+        };
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(cannot_write)?;
+        }
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", self.to_hex()))
+            .map_err(cannot_write)
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+/// Never shows the key itself
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+/// Encrypt the content of the input file `name`, e.g. `day07.txt`
+pub fn encrypt(key: &Key, name: &str, plaintext: &str) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let payload = Payload {
+        msg: plaintext.as_bytes(),
+        aad: name.as_bytes(),
+    };
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, payload)
+        .expect("an input fits in a ChaCha20-Poly1305 message");
+
+    [VERSION, nonce.as_slice(), &ciphertext].concat()
+}
+
+/// Decrypt the content of the input file `name`, `None` when not authentic
+pub fn decrypt(key: &Key, name: &str, data: &[u8]) -> Option<String> {
+    let data = data.strip_prefix(VERSION)?;
+    if data.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let payload = Payload {
+        msg: ciphertext,
+        aad: name.as_bytes(),
+    };
+    let plaintext = key
+        .cipher()
+        .decrypt(Nonce::from_slice(nonce), payload)
+        .ok()?;
+    String::from_utf8(plaintext).ok()
+}
+
+/// Encrypted counterpart of an input, e.g. `day07.txt.enc` for `day07.txt`
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map_or_else(OsString::new, OsString::from);
+    name.push(".enc");
+    path.with_file_name(name)
+}
+
+/// Whether an input is available, in plaintext or encrypted
+pub fn input_exists(path: &Path) -> bool {
+    path.is_file() || encrypted_path(path).is_file()
+}
+
+fn plaintext_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Decrypt the `.enc` counterpart of the input `path`
+pub fn decrypt_input(key: &Key, path: &Path) -> Result<String, CryptError> {
+    let encrypted = encrypted_path(path);
+    let data = fs::read(&encrypted).map_err(|source| CryptError::CannotRead {
+        path: encrypted.clone(),
+        source,
+    })?;
+    if !data.starts_with(VERSION) {
+        return Err(CryptError::NotEncrypted(encrypted));
+    }
+    decrypt(key, &plaintext_name(path), &data).ok_or(CryptError::CannotDecrypt(encrypted))
+}
+
+/// Encrypt the input `path` to its `.enc` counterpart, returning whether it was written
+///
+/// An encrypted file already holding the same input is kept as is, so that
+/// re-encrypting leaves no change to commit.
+pub fn encrypt_input(key: &Key, path: &Path) -> Result<bool, CryptError> {
+    let plaintext = fs::read_to_string(path).map_err(|source| CryptError::CannotRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    if decrypt_input(key, path).is_ok_and(|decrypted| decrypted == plaintext) {
+        return Ok(false);
+    }
+
+    let encrypted = encrypted_path(path);
+    fs::write(&encrypted, encrypt(key, &plaintext_name(path), &plaintext)).map_err(|source| {
+        CryptError::CannotWrite {
+            path: encrypted,
+            source,
+        }
+    })?;
+    Ok(true)
+}
+
+/// Content of an input, decrypted from its `.enc` counterpart when the plaintext is absent
+pub fn read_input_file(path: &Path) -> Result<String, AocInputError> {
+    if path.exists() || !encrypted_path(path).exists() {
+        return read_file(path);
+    }
+    Ok(decrypt_input(&Key::from_env()?, path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn key() -> Key {
+        Key::from_hex(KEY).unwrap()
+    }
+
+    #[test]
+    fn test_key_hex() {
+        assert_eq!(KEY, key().to_hex());
+        assert_eq!(Some(key()), Key::from_hex(&format!("  {}\n", KEY)));
+        assert_eq!(None, Key::from_hex(&KEY[2..]));
+        assert_eq!(None, Key::from_hex(&format!("+f{}", &KEY[2..])));
+        assert_ne!(Key::generate(), Key::generate());
+        assert_eq!("Key(..)", format!("{:?}", key()));
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let data = encrypt(&key(), "day07.txt", "3,0,4,0,99\n");
+
+        assert!(data.starts_with(VERSION));
+        assert!(!data.windows(4).any(|w| w == b"3,0,"));
+        assert_eq!(
+            Some("3,0,4,0,99\n".to_string()),
+            decrypt(&key(), "day07.txt", &data)
+        );
+        // a fresh nonce every time
+        assert_ne!(data, encrypt(&key(), "day07.txt", "3,0,4,0,99\n"));
+    }
+
+    #[test]
+    fn test_decrypt_rejects_unauthentic_data() {
+        let data = encrypt(&key(), "day07.txt", "3,0,4,0,99\n");
+        let mut altered = data.clone();
+        *altered.last_mut().unwrap() ^= 1;
+        let other_key = Key::from_hex(&KEY.replace('0', "f")).unwrap();
+
+        assert_eq!(None, decrypt(&key(), "day07.txt", &altered));
+        assert_eq!(None, decrypt(&other_key, "day07.txt", &data));
+        assert_eq!(None, decrypt(&key(), "day08.txt", &data));
+        assert_eq!(
+            None,
+            decrypt(&key(), "day07.txt", &data[..VERSION.len() + 4])
+        );
+    }
+
+    #[test]
+    fn test_encrypt_input() {
+        let dir = env::temp_dir().join(format!("aoc_crypt_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("day07.txt");
+        fs::write(&path, "3,0,4,0,99\n").unwrap();
+
+        let first = encrypt_input(&key(), &path).unwrap();
+        let unchanged = encrypt_input(&key(), &path).unwrap();
+        fs::write(&path, "99\n").unwrap();
+        let changed = encrypt_input(&key(), &path).unwrap();
+        let decrypted = decrypt_input(&key(), &path);
+        fs::write(encrypted_path(&path), "3,0,4,0,99\n").unwrap();
+        let not_encrypted = decrypt_input(&key(), &path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(first);
+        assert!(!unchanged);
+        assert!(changed);
+        assert_eq!("99\n", decrypted.unwrap());
+        assert!(matches!(not_encrypted, Err(CryptError::NotEncrypted(_))));
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            PathBuf::from("inputs/day07.alice.txt.enc"),
+            encrypted_path(Path::new("inputs/day07.alice.txt"))
+        );
+    }
+
+    #[test]
+    fn test_key_file() {
+        let path = env::temp_dir()
+            .join(format!("aoc_crypt_key_{}", std::process::id()))
+            .join(".aoc_key");
+
+        let missing = Key::load(&path);
+        key().save(&path).unwrap();
+        let loaded = Key::load(&path);
+        let overwritten = Key::generate().save(&path);
+        fs::write(&path, "not a key\n").unwrap();
+        let invalid = Key::load(&path);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert!(matches!(missing, Err(CryptError::NoKey(_))));
+        assert_eq!(key(), loaded.unwrap());
+        assert!(matches!(overwritten, Err(CryptError::CannotWrite { .. })));
+        assert!(matches!(invalid, Err(CryptError::InvalidKey(_))));
+    }
+}
@@ -0,0 +1,290 @@
+//! Compass directions and turns
+//!
+//! Puzzles disagree on which way "up" is: grids read from text have `y` growing
+//! downwards (screen convention), while others plot `y` growing upwards (math
+//! convention). Directions stay abstract, and the convention is chosen when
+//! turning them into offsets with [`YAxis`].
+
+use crate::point::Point2;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid direction `{0}`")]
+pub struct InvalidDirection(pub String);
+
+/// Way the `y` coordinate grows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YAxis {
+    /// Screen convention, as in grids read from text: north is `(0, -1)`
+    Down,
+    /// Math convention: north is `(0, 1)`
+    Up,
+}
+
+impl YAxis {
+    /// Offset `(x, y)` given with `y` growing downwards, in this convention
+    fn orient(self, x: i64, y: i64) -> Point2 {
+        match self {
+            YAxis::Down => Point2::new(x, y),
+            YAxis::Up => Point2::new(x, -y),
+        }
+    }
+}
+
+/// Change of direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Reverse,
+}
+
+/// One of the 4 orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// Offset of a step in this direction
+    pub fn offset(self, y_axis: YAxis) -> Point2 {
+        match self {
+            Direction4::North => y_axis.orient(0, -1),
+            Direction4::East => y_axis.orient(1, 0),
+            Direction4::South => y_axis.orient(0, 1),
+            Direction4::West => y_axis.orient(-1, 0),
+        }
+    }
+
+    /// Quarter turned counterclockwise
+    pub fn left(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Quarter turned clockwise
+    pub fn right(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction4 {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Reverse => self.reverse(),
+        }
+    }
+
+    /// Direction of `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or an arrow `^`/`>`/`v`/`<`
+    pub fn from_char(c: char) -> Option<Direction4> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction4::North),
+            'R' | 'E' | '>' => Some(Direction4::East),
+            'D' | 'S' | 'v' => Some(Direction4::South),
+            'L' | 'W' | '<' => Some(Direction4::West),
+            _ => None,
+        }
+    }
+
+    /// Arrow pointing in this direction, as drawn on screen
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::North => '^',
+            Direction4::East => '>',
+            Direction4::South => 'v',
+            Direction4::West => '<',
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = InvalidDirection;
+
+    /// See [`Direction4::from_char`]
+    fn from_str(s: &str) -> Result<Direction4, InvalidDirection> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Direction4::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(InvalidDirection(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Direction4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Direction8::from(*self))
+    }
+}
+
+/// One of the 8 directions, diagonals included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Offset of a step in this direction
+    pub fn offset(self, y_axis: YAxis) -> Point2 {
+        match self {
+            Direction8::North => y_axis.orient(0, -1),
+            Direction8::NorthEast => y_axis.orient(1, -1),
+            Direction8::East => y_axis.orient(1, 0),
+            Direction8::SouthEast => y_axis.orient(1, 1),
+            Direction8::South => y_axis.orient(0, 1),
+            Direction8::SouthWest => y_axis.orient(-1, 1),
+            Direction8::West => y_axis.orient(-1, 0),
+            Direction8::NorthWest => y_axis.orient(-1, -1),
+        }
+    }
+
+    /// Eighth turned counterclockwise
+    pub fn left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Eighth turned clockwise
+    pub fn right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Turn by an eighth, left or right, or reverse
+    pub fn turn(self, turn: Turn) -> Direction8 {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Reverse => self.reverse(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Direction8::North => "N",
+            Direction8::NorthEast => "NE",
+            Direction8::East => "E",
+            Direction8::SouthEast => "SE",
+            Direction8::South => "S",
+            Direction8::SouthWest => "SW",
+            Direction8::West => "W",
+            Direction8::NorthWest => "NW",
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = InvalidDirection;
+
+    /// Compass point such as `N` or `SW`, or any direction of [`Direction4::from_char`]
+    fn from_str(s: &str) -> Result<Direction8, InvalidDirection> {
+        Direction8::ALL
+            .into_iter()
+            .find(|direction| direction.name() == s)
+            .or_else(|| s.parse::<Direction4>().ok().map(Direction8::from))
+            .ok_or_else(|| InvalidDirection(s.to_string()))
+    }
+}
+
+/// Compass point, e.g. `NE`
+impl fmt::Display for Direction8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Point2::new(0, -1), Direction4::North.offset(YAxis::Down));
+        assert_eq!(Point2::new(0, 1), Direction4::North.offset(YAxis::Up));
+        assert_eq!(Point2::new(1, 0), Direction4::East.offset(YAxis::Up));
+        assert_eq!(
+            Point2::new(-1, 1),
+            Direction8::SouthWest.offset(YAxis::Down)
+        );
+        assert_eq!(Point2::new(-1, -1), Direction8::SouthWest.offset(YAxis::Up));
+        for direction in Direction4::ALL {
+            assert_eq!(
+                direction.offset(YAxis::Down),
+                Direction8::from(direction).offset(YAxis::Down)
+            );
+        }
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction4::West, Direction4::North.left());
+        assert_eq!(Direction4::East, Direction4::North.right());
+        assert_eq!(Direction4::North, Direction4::South.turn(Turn::Reverse));
+        assert_eq!(Direction4::South, Direction4::West.turn(Turn::Left));
+        assert_eq!(Direction8::NorthWest, Direction8::North.left());
+        assert_eq!(Direction8::North, Direction8::NorthWest.right());
+        assert_eq!(Direction8::SouthEast, Direction8::NorthWest.reverse());
+        for direction in Direction8::ALL {
+            assert_eq!(direction, direction.left().right());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<Direction4>();
+
+        assert_eq!(Ok(Direction4::North), parse("U"));
+        assert_eq!(Ok(Direction4::West), parse("W"));
+        assert_eq!(Ok(Direction4::South), parse("v"));
+        assert_eq!(Ok(Direction4::East), parse(">"));
+        assert_eq!(Err(InvalidDirection("UR".to_string())), parse("UR"));
+        assert!(parse("").is_err());
+        assert_eq!(Ok(Direction8::SouthWest), "SW".parse::<Direction8>());
+        assert_eq!(Ok(Direction8::East), "R".parse::<Direction8>());
+        assert_eq!("NE", Direction8::NorthEast.to_string());
+        assert_eq!("W", Direction4::West.to_string());
+        assert_eq!('v', Direction4::South.arrow());
+    }
+}
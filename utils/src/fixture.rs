@@ -0,0 +1,311 @@
+//! Examples holding their expected answers, turned into tests
+//!
+//! An example `inputs/examples/dayXX_N.txt` can start with a header of
+//! `key: value` lines, ended by a `---` line:
+//!
+//! ```text
+//! part1: 4
+//! part2: 32
+//! steps: 10
+//! ---
+//! light red bags contain 1 bright white bag, 2 muted yellow bags.
+//! ...
+//! ```
+//!
+//! `part1` and `part2` are the expected answers, as displayed, and any other
+//! key is a puzzle parameter handed to [`Solution::parse_with`]. Files without
+//! a header are plain examples, the whole file being the input.
+//!
+//! Year crates generate one `#[test]` per example from their build script
+//! with [`write_example_tests`], so that adding an example needs no code.
+//!
+//! [`Solution::parse_with`]: crate::Solution::parse_with
+
+use crate::input::read_file;
+use crate::paths::{current_year, example_path};
+use crate::{AocInputError, DynSolution, Params, Part, Registry};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Line ending the header of a fixture
+const HEADER_END: &str = "---";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fixture {
+    pub input: String,
+    /// Expected answers, as displayed
+    pub expected: BTreeMap<Part, String>,
+    pub params: Params,
+}
+
+impl Fixture {
+    /// Split the header from the input, the whole content being the input
+    /// when it does not start with a header
+    pub fn parse(content: &str) -> Fixture {
+        let mut header = Vec::new();
+        let mut offset = 0;
+        for line in content.split_inclusive('\n') {
+            offset += line.len();
+            let line = line.trim_end();
+            if line == HEADER_END {
+                return Fixture::from_header(&header, &content[offset..]);
+            }
+            match header_entry(line) {
+                Some(entry) => header.push(entry),
+                None => break,
+            }
+        }
+
+        Fixture {
+            input: content.to_string(),
+            ..Fixture::default()
+        }
+    }
+
+    fn from_header(header: &[(&str, &str)], input: &str) -> Fixture {
+        let mut fixture = Fixture {
+            input: input.to_string(),
+            ..Fixture::default()
+        };
+        for &(key, value) in header {
+            match key {
+                "part1" => fixture.expected.insert(Part::One, value.to_string()),
+                "part2" => fixture.expected.insert(Part::Two, value.to_string()),
+                _ => {
+                    fixture.params.insert(key, value);
+                    None
+                }
+            };
+        }
+        fixture
+    }
+
+    pub fn load(path: &Path) -> Result<Fixture, AocInputError> {
+        Ok(Fixture::parse(&read_file(path)?))
+    }
+
+    /// Solve the fixture, describing every answer differing from the expected one
+    pub fn check(&self, solution: &dyn DynSolution) -> Result<(), String> {
+        let parsed = solution
+            .parse_with(&self.input, &self.params)
+            .map_err(|e| format!("cannot parse input: {}", e))?;
+
+        let mut mismatches = Vec::new();
+        for (&part, expected) in self.expected.iter() {
+            match solution.solve(part, parsed.as_ref()) {
+                Ok(answer) if answer.matches(expected) => {}
+                Ok(answer) => mismatches.push(format!(
+                    "part {}: expected `{}`, got `{}`",
+                    part, expected, answer
+                )),
+                Err(e) => mismatches.push(format!("part {}: {}", part, e)),
+            }
+        }
+
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches.join("\n")),
+        }
+    }
+}
+
+/// `key: value` line of a header
+fn header_entry(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    let is_key = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    is_key.then(|| (key, value.trim()))
+}
+
+/// Numbers of the examples of a day in `examples_dir`, sorted
+pub fn example_numbers(examples_dir: &Path, day: u8) -> Vec<usize> {
+    let prefix = format!("day{:02}_", day);
+    let mut numbers: Vec<usize> = fs::read_dir(examples_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            name.strip_prefix(&prefix)?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    numbers.sort();
+    numbers
+}
+
+/// Check example `n` of a day against the answers of its header, panicking on mismatch
+///
+/// Called by the tests generated by [`write_example_tests`].
+pub fn check_example(register: fn(&mut Registry), year: u16, day: u8, n: usize) {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    let solution = registry
+        .get(year, day)
+        .unwrap_or_else(|| panic!("no solution registered for {} day {:02}", year, day));
+
+    let path = example_path(year, day, n);
+    let fixture = Fixture::load(&path).unwrap_or_else(|e| panic!("{}", e));
+    if let Err(mismatches) = fixture.check(solution) {
+        panic!("{}:\n{}", path.display(), mismatches);
+    }
+}
+
+/// Source of one test per example of `examples_dir`
+///
+/// Examples without expected answers get an ignored test, as a reminder.
+pub fn example_tests(examples_dir: &Path, year: u16) -> String {
+    let mut tests = String::from(
+        "// Generated by `aoc_utils::fixture::write_example_tests` from `inputs/examples/`\n",
+    );
+    for day in 1..=25 {
+        for n in example_numbers(examples_dir, day) {
+            let path = examples_dir.join(format!("day{:02}_{}.txt", day, n));
+            let has_answers = Fixture::load(&path).is_ok_and(|f| !f.expected.is_empty());
+
+            tests.push_str("\n#[test]\n");
+            if !has_answers {
+                tests.push_str(&format!(
+                    "#[ignore = \"no expected answers in day{:02}_{}.txt\"]\n",
+                    day, n
+                ));
+            }
+            tests.push_str(&format!(
+                "fn day{day:02}_example{n}() {{\n    \
+                 ::aoc_utils::fixture::check_example(crate::register, {year}, {day}, {n});\n}}\n"
+            ));
+        }
+    }
+    tests
+}
+
+/// Write `example_tests.rs` into `OUT_DIR`, for the build script of a year crate
+///
+/// The year crate includes it in its `lib.rs`:
+/// ```ignore
+/// #[cfg(test)]
+/// mod example_tests {
+///     include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+/// }
+/// ```
+pub fn write_example_tests() {
+    let year = current_year().expect("year crates are in a `years/<year>` directory");
+    let examples_dir = example_path(year, 1, 1)
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    rerun_if_changed(&examples_dir);
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("run by cargo"));
+    fs::write(
+        out_dir.join("example_tests.rs"),
+        example_tests(&examples_dir, year),
+    )
+    .expect("cannot write example tests");
+}
+
+/// Have cargo re-run the build script when `path` changes
+///
+/// The closest existing ancestor is watched instead of a missing path, to
+/// notice its creation.
+pub(crate) fn rerun_if_changed(path: &Path) {
+    if let Some(watched) = path.ancestors().find(|ancestor| ancestor.exists()) {
+        println!("cargo:rerun-if-changed={}", watched.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Solution, SolutionError};
+
+    #[test]
+    fn test_parse_with_header() {
+        let fixture = Fixture::parse("part1: 4\npart2: some text\nsteps: 10\n---\n#.#\n...\n");
+
+        assert_eq!("#.#\n...\n", fixture.input);
+        assert_eq!(Some(&"4".to_string()), fixture.expected.get(&Part::One));
+        assert_eq!(
+            Some(&"some text".to_string()),
+            fixture.expected.get(&Part::Two)
+        );
+        assert_eq!(Some(10), fixture.params.get::<u32>("steps").unwrap());
+    }
+
+    #[test]
+    fn test_parse_without_header() {
+        for content in ["#.#\n---\n", "a: b\nc\n---\n", "1 2\n3 4\n", ""] {
+            let fixture = Fixture::parse(content);
+
+            assert_eq!(content, fixture.input);
+            assert!(fixture.expected.is_empty());
+            assert!(fixture.params.is_empty());
+        }
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        type Input = (usize, usize);
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Self::parse_with(input, &Params::new())
+        }
+
+        fn parse_with(input: &str, params: &Params) -> Result<Self::Input, SolutionError> {
+            Ok((input.lines().count(), params.get("factor")?.unwrap_or(1)))
+        }
+
+        fn part1(&(lines, factor): &Self::Input) -> Result<Answer, SolutionError> {
+            Ok((lines * factor).into())
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut registry = Registry::new();
+        registry.register::<Count>();
+        let solution = registry.get(2000, 1).unwrap();
+
+        assert!(Fixture::parse("part1: 3\n---\na\nb\nc\n")
+            .check(solution)
+            .is_ok());
+        assert!(Fixture::parse("part1: 6\nfactor: 2\n---\na\nb\nc\n")
+            .check(solution)
+            .is_ok());
+        assert_eq!(
+            Err("part 1: expected `4`, got `3`".to_string()),
+            Fixture::parse("part1: 4\n---\na\nb\nc\n").check(solution)
+        );
+        assert_eq!(
+            Err("part 2: expected `1`, got `unsolved`".to_string()),
+            Fixture::parse("part2: 1\n---\na\n").check(solution)
+        );
+    }
+
+    #[test]
+    fn test_example_tests() {
+        let dir = env::temp_dir().join(format!("aoc_fixture_examples_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07_2.txt"), "part1: 4\n---\nabc\n").unwrap();
+        fs::write(dir.join("day07_1.txt"), "abc\n").unwrap();
+
+        let tests = example_tests(&dir, 2020);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(tests.contains(
+            "#[test]\n#[ignore = \"no expected answers in day07_1.txt\"]\nfn day07_example1() {"
+        ));
+        assert!(tests.contains(
+            "#[test]\nfn day07_example2() {\n    \
+             ::aoc_utils::fixture::check_example(crate::register, 2020, 7, 2);\n}\n"
+        ));
+    }
+}
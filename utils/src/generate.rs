@@ -0,0 +1,164 @@
+//! Random valid inputs, for stress and scaling tests
+//!
+//! A day implementing [`Generator`] produces inputs of any size from a seed,
+//! the same seed always giving the same input. Generators are registered with
+//! their solution, see [`Registry::register_generator`], and fed to it by
+//! `aoc generate` and `aoc scale`.
+//!
+//! [`Registry::register_generator`]: crate::Registry::register_generator
+
+use crate::Solution;
+use std::ops::RangeInclusive;
+
+/// Generator of valid inputs of a day
+pub trait Generator: Solution {
+    /// What the size of a generated input counts, e.g. `"levels per report"`
+    const SIZE: &'static str;
+
+    /// Input of the given size, drawn from `rng`
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Small seeded random generator (SplitMix64), stable across platforms and releases
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value below `bound`, which must not be 0
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Uniform value of a non-empty range
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        match end.wrapping_sub(start) as u64 {
+            u64::MAX => self.next_u64() as i64,
+            span => start.wrapping_add(self.below(span + 1) as i64),
+        }
+    }
+
+    /// Whether an event of the given probability happens
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Uniform element of a non-empty slice
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// Registered generator of a day
+#[derive(Debug, Clone, Copy)]
+pub struct InputGenerator {
+    pub year: u16,
+    pub day: u8,
+    /// What the size of a generated input counts
+    pub size: &'static str,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl InputGenerator {
+    pub fn new<G: Generator>() -> InputGenerator {
+        InputGenerator {
+            year: G::YEAR,
+            day: G::DAY,
+            size: G::SIZE,
+            generate: G::generate,
+        }
+    }
+
+    /// Input of the given size, the same for the same seed
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, SolutionError};
+
+    #[test]
+    fn test_rng_is_seeded() {
+        let draws = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(draws(1), draws(1));
+        assert_ne!(draws(1), draws(2));
+        // SplitMix64 reference output for seed 0
+        assert_eq!(0xe220_a839_7b1d_cdaf, Rng::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::new(7);
+        let values: Vec<i64> = (0..1000).map(|_| rng.range(-2..=2)).collect();
+
+        assert!(values.iter().all(|v| (-2..=2).contains(v)));
+        for v in -2..=2 {
+            assert!(values.contains(&v));
+        }
+        assert_eq!(5, rng.range(5..=5));
+        assert_eq!(&'a', rng.choose(&['a']));
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+        rng.range(i64::MIN..=i64::MAX);
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        type Input = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Answer, SolutionError> {
+            Ok((*lines).into())
+        }
+    }
+
+    impl Generator for Lines {
+        const SIZE: &'static str = "lines";
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+        }
+    }
+
+    #[test]
+    fn test_input_generator() {
+        let generator = InputGenerator::new::<Lines>();
+
+        assert_eq!(
+            (2000, 1, "lines"),
+            (generator.year, generator.day, generator.size)
+        );
+        assert_eq!(5, generator.generate(3, 5).lines().count());
+        assert_eq!(generator.generate(3, 5), generator.generate(3, 5));
+        assert_ne!(generator.generate(3, 5), generator.generate(4, 5));
+    }
+}
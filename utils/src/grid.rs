@@ -0,0 +1,581 @@
+//! Dense rectangular grids, stored row by row in a single vector
+//!
+//! Cells are addressed by [`Point2`], `(0, 0)` being the top-left cell. Lookups
+//! outside of the grid return `None` rather than wrapping or panicking, so
+//! neighbours can be probed without bound checks of their own.
+
+use crate::direction::{Direction4, Direction8, YAxis};
+use crate::point::Point2;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("{len} cells cannot fill a {width}x{height} grid")]
+    WrongSize {
+        len: usize,
+        width: usize,
+        height: usize,
+    },
+    #[error("row {row} has {len} cells instead of {width}")]
+    Ragged {
+        row: usize,
+        len: usize,
+        width: usize,
+    },
+    #[error("{line}:{column}: invalid cell `{cell}`")]
+    InvalidCell {
+        /// 1-based line number
+        line: usize,
+        /// 1-based column, in characters
+        column: usize,
+        cell: char,
+    },
+}
+
+/// Rectangular grid of cells
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given cells, row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>, GridError> {
+        if cells.len() != width * height {
+            return Err(GridError::WrongSize {
+                len: cells.len(),
+                width,
+                height,
+            });
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Grid of the given rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, GridError> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, cells_of_row) in rows.into_iter().enumerate() {
+            if cells_of_row.len() != width {
+                return Err(GridError::Ragged {
+                    row,
+                    len: cells_of_row.len(),
+                    width,
+                });
+            }
+            cells.extend(cells_of_row);
+        }
+        Grid::new(width, height, cells)
+    }
+
+    /// Grid whose cells are computed from their position
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point2) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2::new(x as i64, y as i64)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of the lines of `text`, mapping each character to a cell
+    ///
+    /// # Example
+    /// ```
+    /// use aoc_utils::Grid;
+    ///
+    /// let grid = Grid::parse("#.\n.#\n", |c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// })
+    /// .unwrap();
+    /// assert_eq!((2, 2), (grid.width(), grid.height()));
+    /// ```
+    pub fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, GridError> {
+        let rows = text
+            .lines()
+            .enumerate()
+            .map(|(line, chars)| {
+                chars
+                    .chars()
+                    .enumerate()
+                    .map(|(column, c)| {
+                        cell(c).ok_or(GridError::InvalidCell {
+                            line: line + 1,
+                            column: column + 1,
+                            cell: c,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y)
+    }
+
+    fn index_of(&self, p: Point2) -> Option<usize> {
+        match self.contains(p) {
+            true => Some(p.y as usize * self.width + p.x as usize),
+            false => None,
+        }
+    }
+
+    pub fn get(&self, p: Point2) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point2) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Cells row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Positions row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let width = self.width;
+        (0..self.height as i64).flat_map(move |y| (0..width as i64).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Positions and cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, satisfying `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        match y < self.height {
+            true => Some(&self.cells[y * self.width..(y + 1) * self.width]),
+            false => None,
+        }
+    }
+
+    /// Rows, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // unlike `chunks`, empty rows are yielded when the width is 0
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        match x < self.width {
+            // `skip` rather than slicing, which is out of bounds when the height is 0
+            true => Some(self.cells.iter().skip(x).step_by(self.width)),
+            false => None,
+        }
+    }
+
+    /// Columns, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// Orthogonal neighbours of `p` within the grid, clockwise from the one above
+    pub fn neighbours4(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| p + direction.offset(YAxis::Down))
+            .filter(|&n| self.contains(n))
+    }
+
+    /// Neighbours of `p` within the grid, diagonals included, clockwise from the one above
+    pub fn neighbours8(&self, p: Point2) -> impl Iterator<Item = Point2> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| p + direction.offset(YAxis::Down))
+            .filter(|&n| self.contains(n))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// View of the `width` x `height` sub-grid starting at `origin`, if it fits in the grid
+    pub fn view(&self, origin: Point2, width: usize, height: usize) -> Option<GridView<'_, T>> {
+        let fits = origin.x >= 0
+            && origin.y >= 0
+            && origin.x as usize + width <= self.width
+            && origin.y as usize + height <= self.height;
+        match fits {
+            true => Some(GridView {
+                grid: self,
+                origin,
+                width,
+                height,
+            }),
+            false => None,
+        }
+    }
+
+    /// Rendering of the grid, each cell being drawn by `render`
+    ///
+    /// # Example
+    /// ```
+    /// use aoc_utils::Grid;
+    ///
+    /// let grid = Grid::new(2, 1, vec![true, false]).unwrap();
+    /// assert_eq!("#.", grid.display(|&on| if on { '#' } else { '.' }).to_string());
+    /// ```
+    pub fn display<F, D>(&self, render: F) -> GridDisplay<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: fmt::Display,
+    {
+        GridDisplay {
+            grid: self,
+            render,
+            separator: "",
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid mirrored along its main diagonal, rows becoming columns
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point2::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Grid turned a quarter clockwise
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let height = self.height as i64;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point2::new(p.y, height - 1 - p.x)].clone()
+        })
+    }
+
+    /// Grid turned a quarter counterclockwise
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        let width = self.width as i64;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Point2::new(width - 1 - p.y, p.x)].clone()
+        })
+    }
+
+    /// Grid mirrored left to right
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width as i64;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point2::new(width - 1 - p.x, p.y)].clone()
+        })
+    }
+
+    /// Grid mirrored top to bottom
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height as i64;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Point2::new(p.x, height - 1 - p.y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `p` is outside of the grid, see [`Grid::get`]
+    fn index(&self, p: Point2) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{} is outside of a {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, p: Point2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{} is outside of a {}x{} grid", p, width, height),
+        }
+    }
+}
+
+/// Cells drawn with their `Display`, rows separated by newlines
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rendering of a grid, see [`Grid::display`]
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+    separator: &'a str,
+}
+
+impl<'a, T, F> GridDisplay<'a, T, F> {
+    /// Separate the cells of a row, e.g. by a space for multi-digit numbers
+    pub fn separator(self, separator: &'a str) -> GridDisplay<'a, T, F> {
+        GridDisplay { separator, ..self }
+    }
+}
+
+impl<T, F, D> fmt::Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                if x > 0 {
+                    write!(f, "{}", self.separator)?;
+                }
+                write!(f, "{}", (self.render)(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rectangular part of a grid, see [`Grid::view`]
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point2,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cell at `p`, relative to the origin of the view
+    pub fn get(&self, p: Point2) -> Option<&'a T> {
+        match (0..self.width as i64).contains(&p.x) && (0..self.height as i64).contains(&p.y) {
+            true => self.grid.get(self.origin + p),
+            false => None,
+        }
+    }
+
+    /// Rows of the view, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (x, width) = (self.origin.x as usize, self.width);
+        let grid = self.grid;
+        (self.origin.y as usize..self.origin.y as usize + self.height)
+            .map(move |y| &grid.row(y).expect("view within its grid")[x..x + width])
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Copy of the viewed cells
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::parse(text, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get(Point2::new(2, 1)));
+        assert_eq!(None, grid.get(Point2::new(3, 0)));
+        assert_eq!(None, grid.get(Point2::new(-1, 0)));
+        assert_eq!(
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                cell: 'x'
+            }),
+            Grid::parse("12\n3x\n", |c| c.to_digit(10))
+        );
+        assert_eq!(
+            Err(GridError::Ragged {
+                row: 1,
+                len: 1,
+                width: 2
+            }),
+            Grid::parse("12\n3\n", |c| c.to_digit(10))
+        );
+        assert!(Grid::new(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = digits("12\n34\n");
+        *grid.get_mut(Point2::new(0, 1)).unwrap() = 7;
+        grid[Point2::new(1, 1)] += 1;
+
+        assert_eq!("12\n75", grid.to_string());
+        assert!(grid.get_mut(Point2::new(2, 0)).is_none());
+        assert_eq!(Some(Point2::new(1, 1)), grid.position(|&d| d == 5));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]],
+            grid.rows().collect::<Vec<&[u32]>>()
+        );
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            grid.columns()
+                .map(|column| column.copied().collect())
+                .collect::<Vec<Vec<u32>>>()
+        );
+        assert_eq!(Some(&[4, 5, 6][..]), grid.row(1));
+        assert!(grid.row(2).is_none() && grid.column(3).is_none());
+        assert_eq!(
+            vec![(Point2::new(0, 1), &4)],
+            grid.iter().filter(|(_, &d)| d == 4).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns_empty() {
+        let flat: Grid<u32> = Grid::new(3, 0, vec![]).unwrap();
+        assert_eq!(0, flat.rows().count());
+        assert_eq!(
+            vec![0, 0, 0],
+            flat.columns()
+                .map(|column| column.count())
+                .collect::<Vec<usize>>()
+        );
+        assert_eq!(Some(0), flat.column(2).map(|column| column.count()));
+
+        let thin: Grid<u32> = Grid::new(0, 2, vec![]).unwrap();
+        assert_eq!(
+            vec![0, 0],
+            thin.rows().map(|row| row.len()).collect::<Vec<usize>>()
+        );
+        assert_eq!(0, thin.columns().count());
+        assert!(thin.column(0).is_none());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n");
+
+        assert_eq!(
+            vec![Point2::new(1, 0), Point2::new(0, 1)],
+            grid.neighbours4(Point2::ORIGIN).collect::<Vec<Point2>>()
+        );
+        assert_eq!(4, grid.neighbours4(Point2::new(1, 1)).count());
+        assert_eq!(3, grid.neighbours8(Point2::new(2, 2)).count());
+        assert_eq!(
+            vec![2, 3, 6, 9, 8, 7, 4, 1],
+            grid.neighbours8(Point2::new(1, 1))
+                .map(|p| grid[p])
+                .collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!("14\n25\n36", grid.transpose().to_string());
+        assert_eq!("41\n52\n63", grid.rotate_clockwise().to_string());
+        assert_eq!("36\n25\n14", grid.rotate_counterclockwise().to_string());
+        assert_eq!("321\n654", grid.flip_horizontal().to_string());
+        assert_eq!("456\n123", grid.flip_vertical().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = digits("1234\n5678\n9012\n");
+        let view = grid.view(Point2::new(1, 1), 2, 2).unwrap();
+
+        assert_eq!((2, 2), (view.width(), view.height()));
+        assert_eq!(Some(&6), view.get(Point2::ORIGIN));
+        assert_eq!(None, view.get(Point2::new(2, 0)));
+        assert_eq!("67\n01", view.to_grid().to_string());
+        assert!(grid.view(Point2::new(3, 0), 2, 1).is_none());
+        assert!(grid.view(Point2::new(0, 0), 4, 3).is_some());
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::new(3, 1, vec![1, 20, 300]).unwrap();
+
+        assert_eq!("1 20 300", grid.display(|&n| n).separator(" ").to_string());
+        assert_eq!(
+            "#..",
+            grid.display(|&n| if n < 10 { '#' } else { '.' })
+                .to_string()
+        );
+    }
+}
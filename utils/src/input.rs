@@ -0,0 +1,189 @@
+//! Fallible parsing of input files, locating every error in its file
+
+use crate::crypt::CryptError;
+use crate::grid::{Grid, GridError};
+use std::any::type_name;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AocInputError {
+    #[error("cannot read `{path}`: {source}")]
+    CannotRead { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Decrypt(#[from] CryptError),
+    #[error("{}:{line}:{column}: cannot parse `{text}` as `{type_name}`", .path.display())]
+    CannotParse {
+        path: PathBuf,
+        /// 1-based line number
+        line: usize,
+        /// 1-based column, in characters
+        column: usize,
+        text: String,
+        type_name: &'static str,
+    },
+    #[error("{}: {source}", .path.display())]
+    InvalidGrid { path: PathBuf, source: GridError },
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<String, AocInputError> {
+    fs::read_to_string(path).map_err(|source| AocInputError::CannotRead {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Line and column of a byte offset of `content`, both 1-based
+fn position(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Error for `text`, a slice of `content` which could not be parsed as a `T`
+fn cannot_parse<T>(path: &Path, content: &str, text: &str) -> AocInputError {
+    let offset = text.as_ptr() as usize - content.as_ptr() as usize;
+    let (line, column) = position(content, offset);
+    AocInputError::CannotParse {
+        path: path.to_path_buf(),
+        line,
+        column,
+        text: text.to_string(),
+        type_name: type_name::<T>(),
+    }
+}
+
+fn parse_at<T: FromStr>(path: &Path, content: &str, text: &str) -> Result<T, AocInputError> {
+    text.parse::<T>()
+        .map_err(|_| cannot_parse::<T>(path, content, text))
+}
+
+pub(crate) fn parse_lines<T: FromStr>(path: &Path, content: &str) -> Result<Vec<T>, AocInputError> {
+    content
+        .lines()
+        .map(|line| parse_at(path, content, line))
+        .collect()
+}
+
+pub(crate) fn parse_csv<T: FromStr>(path: &Path, content: &str) -> Result<Vec<T>, AocInputError> {
+    content
+        .trim()
+        .split(',')
+        .map(|value| parse_at(path, content, value.trim()))
+        .collect()
+}
+
+pub(crate) fn parse_digit_grid(path: &Path, content: &str) -> Result<Vec<Vec<u8>>, AocInputError> {
+    content
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| match c.to_digit(10) {
+                    Some(digit) => Ok(digit as u8),
+                    None => Err(cannot_parse::<u8>(
+                        path,
+                        content,
+                        &line[i..i + c.len_utf8()],
+                    )),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn parse_grid<T>(
+    path: &Path,
+    content: &str,
+    cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, AocInputError> {
+    Grid::parse(content, cell).map_err(|source| match source {
+        GridError::InvalidCell { line, column, cell } => AocInputError::CannotParse {
+            path: path.to_path_buf(),
+            line,
+            column,
+            text: cell.to_string(),
+            type_name: type_name::<T>(),
+        },
+        source => AocInputError::InvalidGrid {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines_error_position() {
+        let path = Path::new("inputs/day01.txt");
+
+        assert_eq!(vec![1, 2], parse_lines::<i32>(path, "1\n2\n").unwrap());
+        match parse_lines::<i32>(path, "1\n2\nthree\n") {
+            Err(AocInputError::CannotParse {
+                line,
+                column,
+                text,
+                type_name,
+                ..
+            }) => {
+                assert_eq!((3, 1), (line, column));
+                assert_eq!("three", text);
+                assert_eq!("i32", type_name);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_error_position() {
+        let error = parse_csv::<u8>(Path::new("inputs/day02.txt"), "1,2,\n 3, 300\n").unwrap_err();
+
+        assert_eq!(
+            "inputs/day02.txt:2:5: cannot parse `300` as `u8`",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_digit_grid_error_position() {
+        let path = Path::new("inputs/day03.txt");
+
+        assert_eq!(
+            vec![vec![1, 2], vec![3, 4]],
+            parse_digit_grid(path, "12\n34\n").unwrap()
+        );
+        assert_eq!(
+            "inputs/day03.txt:2:2: cannot parse `x` as `u8`",
+            parse_digit_grid(path, "12\n3x\n").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_errors() {
+        let path = Path::new("inputs/day03.txt");
+        let digit = |c: char| c.to_digit(10);
+
+        assert_eq!(
+            Some(&4),
+            parse_grid(path, "12\n34\n", digit)
+                .unwrap()
+                .get((1, 1).into())
+        );
+        assert_eq!(
+            "inputs/day03.txt:2:2: cannot parse `x` as `u32`",
+            parse_grid(path, "12\n3x\n", digit).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "inputs/day03.txt: row 1 has 1 cells instead of 2",
+            parse_grid(path, "12\n3\n", digit).unwrap_err().to_string()
+        );
+    }
+}
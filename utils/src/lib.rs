@@ -1,20 +1,115 @@
 //! Common utilities for Advent of Code solutions across all years
 
-use std::fs;
+use std::path::{Path, PathBuf};
+
+pub mod alloc;
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod crypt;
+pub mod direction;
+pub mod fixture;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod paths;
+pub mod point;
+pub mod regression;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+
+pub use alloc::AllocStats;
+pub use answers::{KnownAnswers, Verdict};
+pub use direction::{Direction4, Direction8, Turn, YAxis};
+pub use fixture::Fixture;
+pub use generate::{Generator, Rng};
+pub use grid::{Grid, GridError};
+pub use input::AocInputError;
+pub use paths::{
+    answers_path, example_path, input_dir, input_path, input_variants, variant_input_path,
+};
+pub use point::{Point2, Point3};
+pub use solution::{Answer, DynSolution, Params, Part, Registry, Solution, SolutionError};
+pub use sparse_grid::{Bounds, SparseGrid};
+
+/// Input of a day of the running year crate, resolved as described in [`paths`]
+fn day_input_path(day: u8) -> PathBuf {
+    match paths::current_year() {
+        Some(year) => paths::input_path(year, day),
+        None => PathBuf::from(format!("inputs/day{:02}.txt", day)),
+    }
+}
+
+/// Parse the input at `path`, decrypted from its `.enc` counterpart when needed
+fn parse_input_file<T>(
+    path: &Path,
+    parse: impl FnOnce(&Path, &str) -> Result<T, AocInputError>,
+) -> Result<T, AocInputError> {
+    parse(path, &crypt::read_input_file(path)?)
+}
 
 /// Read input file from the inputs directory
 ///
+/// Within a year crate, the file is found whatever the working directory
+/// (see [`paths`]), otherwise it is read from `inputs/` relative to it.
+///
 /// # Arguments
 /// * `day` - Day number (1-25)
 ///
 /// # Example
 /// ```ignore
+/// let input = aoc_utils::try_read_input(1)?;
+/// ```
+pub fn try_read_input(day: u8) -> Result<String, AocInputError> {
+    crypt::read_input_file(&day_input_path(day))
+}
+
+/// Read input file from the inputs directory
+///
+/// # Arguments
+/// * `day` - Day number (1-25)
+///
+/// # Panics
+/// Panics if the file cannot be read, see [`try_read_input`]
+///
+/// # Example
+/// ```ignore
 /// let input = aoc_utils::read_input(1);
 /// ```
 pub fn read_input(day: u8) -> String {
-    let filename = format!("inputs/day{:02}.txt", day);
-    fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read input file: {}", filename))
+    try_read_input(day).unwrap_or_else(|e| {
+        panic!(
+            "{} (download it with `cargo run -p aoc -- fetch <year> {}`)",
+            e, day
+        )
+    })
+}
+
+/// Read an example extracted from the puzzle description, without its fixture header
+///
+/// # Arguments
+/// * `day` - Day number (1-25)
+/// * `n` - Example number, in order of appearance (starting at 1)
+///
+/// # Example
+/// ```ignore
+/// let example = aoc_utils::read_example(1, 1);
+/// ```
+pub fn read_example(day: u8, n: usize) -> String {
+    let path = match paths::current_year() {
+        Some(year) => paths::example_path(year, day, n),
+        None => PathBuf::from(format!("inputs/examples/day{:02}_{}.txt", day, n)),
+    };
+    match Fixture::load(&path) {
+        Ok(fixture) => fixture.input,
+        Err(_) => panic!(
+            "Failed to read example file: {} (extract it with `cargo run -p aoc -- puzzle <year> {}`)",
+            path.display(),
+            day
+        ),
+    }
 }
 
 /// Read input and split into lines
@@ -24,10 +119,32 @@ pub fn read_input(day: u8) -> String {
 ///
 /// # Returns
 /// Vector of strings, one per line
+pub fn try_read_lines(day: u8) -> Result<Vec<String>, AocInputError> {
+    Ok(try_read_input(day)?
+        .lines()
+        .map(|s| s.to_string())
+        .collect())
+}
+
+/// Read input and split into lines, panicking on failure
 pub fn read_lines(day: u8) -> Vec<String> {
     read_input(day).lines().map(|s| s.to_string()).collect()
 }
 
+/// Parse input lines into a specific type
+///
+/// # Arguments
+/// * `day` - Day number (1-25)
+///
+/// # Returns
+/// Vector of parsed values, or the position of the first line which cannot be parsed
+pub fn try_parse_lines<T>(day: u8) -> Result<Vec<T>, AocInputError>
+where
+    T: std::str::FromStr,
+{
+    parse_input_file(&day_input_path(day), input::parse_lines)
+}
+
 /// Parse input lines into a specific type
 ///
 /// # Arguments
@@ -37,16 +154,18 @@ pub fn read_lines(day: u8) -> Vec<String> {
 /// Vector of parsed values
 ///
 /// # Panics
-/// Panics if any line cannot be parsed
+/// Panics if any line cannot be parsed, see [`try_parse_lines`]
 pub fn parse_lines<T>(day: u8) -> Vec<T>
 where
     T: std::str::FromStr,
     <T as std::str::FromStr>::Err: std::fmt::Debug,
 {
-    read_lines(day)
-        .iter()
-        .map(|s| s.parse::<T>().expect("Failed to parse line"))
-        .collect()
+    try_parse_lines(day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read input as a single string, trimming whitespace
+pub fn try_read_input_trimmed(day: u8) -> Result<String, AocInputError> {
+    Ok(try_read_input(day)?.trim().to_string())
 }
 
 /// Read input as a single string, trimming whitespace
@@ -54,6 +173,14 @@ pub fn read_input_trimmed(day: u8) -> String {
     read_input(day).trim().to_string()
 }
 
+/// Split input by blank lines (useful for grouped data)
+pub fn try_read_groups(day: u8) -> Result<Vec<String>, AocInputError> {
+    Ok(try_read_input(day)?
+        .split("\n\n")
+        .map(|s| s.to_string())
+        .collect())
+}
+
 /// Split input by blank lines (useful for grouped data)
 pub fn read_groups(day: u8) -> Vec<String> {
     read_input(day)
@@ -63,15 +190,28 @@ pub fn read_groups(day: u8) -> Vec<String> {
 }
 
 /// Parse input as comma-separated values
+pub fn try_read_csv<T>(day: u8) -> Result<Vec<T>, AocInputError>
+where
+    T: std::str::FromStr,
+{
+    parse_input_file(&day_input_path(day), input::parse_csv)
+}
+
+/// Parse input as comma-separated values, panicking on failure
 pub fn read_csv<T>(day: u8) -> Vec<T>
 where
     T: std::str::FromStr,
     <T as std::str::FromStr>::Err: std::fmt::Debug,
 {
-    read_input_trimmed(day)
-        .split(',')
-        .map(|s| s.trim().parse::<T>().expect("Failed to parse CSV value"))
-        .collect()
+    try_read_csv(day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read input as a 2D grid of characters
+pub fn try_read_char_grid(day: u8) -> Result<Vec<Vec<char>>, AocInputError> {
+    Ok(try_read_lines(day)?
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect())
 }
 
 /// Read input as a 2D grid of characters
@@ -82,20 +222,87 @@ pub fn read_char_grid(day: u8) -> Vec<Vec<char>> {
         .collect()
 }
 
+/// Read input as a [`Grid`], mapping each character to a cell
+///
+/// # Example
+/// ```ignore
+/// let map = aoc_utils::try_read_grid(3, |c| match c {
+///     '#' => Some(true),
+///     '.' => Some(false),
+///     _ => None,
+/// })?;
+/// ```
+pub fn try_read_grid<T>(
+    day: u8,
+    cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, AocInputError> {
+    parse_input_file(&day_input_path(day), |path, content| {
+        input::parse_grid(path, content, cell)
+    })
+}
+
+/// Read input as a [`Grid`], panicking on failure
+pub fn read_grid<T>(day: u8, cell: impl FnMut(char) -> Option<T>) -> Grid<T> {
+    try_read_grid(day, cell).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read input as a [`Grid`] of characters
+pub fn try_read_grid_of_chars(day: u8) -> Result<Grid<char>, AocInputError> {
+    try_read_grid(day, Some)
+}
+
+/// Read input as a [`Grid`] of characters, panicking on failure
+pub fn read_grid_of_chars(day: u8) -> Grid<char> {
+    read_grid(day, Some)
+}
+
+/// Read input as a [`Grid`] of digits (0-9)
+pub fn try_read_grid_of_digits(day: u8) -> Result<Grid<u8>, AocInputError> {
+    try_read_grid(day, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
+/// Read input as a [`Grid`] of digits (0-9), panicking on failure
+pub fn read_grid_of_digits(day: u8) -> Grid<u8> {
+    read_grid(day, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
 /// Read input as a 2D grid of digits (0-9)
+pub fn try_read_digit_grid(day: u8) -> Result<Vec<Vec<u8>>, AocInputError> {
+    parse_input_file(&day_input_path(day), input::parse_digit_grid)
+}
+
+/// Read input as a 2D grid of digits (0-9), panicking on failure
 pub fn read_digit_grid(day: u8) -> Vec<Vec<u8>> {
-    read_lines(day)
-        .iter()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).expect("Invalid digit") as u8)
-                .collect()
-        })
-        .collect()
+    try_read_digit_grid(day).unwrap_or_else(|e| panic!("{}", e))
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::crypt::{encrypt_input, Key, KEY_FILE_VAR};
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_parse_encrypted_input() {
+        let dir = env::temp_dir().join(format!("aoc_utils_encrypted_{}", std::process::id()));
+        let key = Key::generate();
+        key.save(&dir.join(".aoc_key")).unwrap();
+        let path = dir.join("day01.txt");
+        fs::write(&path, "1\n2\n3\n").unwrap();
+        encrypt_input(&key, &path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // no other test of this crate reads the key from the environment
+        env::set_var(KEY_FILE_VAR, dir.join(".aoc_key"));
+        let numbers = parse_input_file(&path, input::parse_lines::<i32>);
+        let csv = parse_input_file(&path, input::parse_csv::<String>);
+        env::remove_var(KEY_FILE_VAR);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec![1, 2, 3], numbers.unwrap());
+        assert_eq!(vec!["1\n2\n3".to_string()], csv.unwrap());
+    }
 
     #[test]
     fn test_parse_numbers() {
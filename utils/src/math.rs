@@ -0,0 +1,210 @@
+//! Number theory: gcd, lcm, modular arithmetic and the Chinese remainder theorem
+//!
+//! Modular functions work on `i64` values with `i128` intermediates, so
+//! products never overflow, and return values in `0..modulus`.
+
+use std::ops::{Div, Mul, Rem};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CrtError {
+    #[error("invalid modulus {0}, moduli must be positive")]
+    InvalidModulus(i64),
+    #[error("x ≡ {0} (mod {1}) and x ≡ {2} (mod {3}) have no common solution")]
+    Incompatible(i64, i64, i64, i64),
+    #[error("combined modulus overflows `i64`")]
+    Overflow,
+}
+
+/// Primitive integer type, signed or not
+pub trait Integer:
+    Copy + Ord + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+
+    /// Absolute value, the identity for unsigned types
+    fn magnitude(self) -> Self;
+}
+
+macro_rules! integer {
+    (signed: $($signed:ty),+; unsigned: $($unsigned:ty),+) => {
+        $(impl Integer for $signed {
+            const ZERO: $signed = 0;
+
+            fn magnitude(self) -> $signed {
+                self.abs()
+            }
+        })+
+        $(impl Integer for $unsigned {
+            const ZERO: $unsigned = 0;
+
+            fn magnitude(self) -> $unsigned {
+                self
+            }
+        })+
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, never negative, `gcd(0, 0)` being 0
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.magnitude()
+}
+
+/// Least common multiple, never negative, 0 when either number is
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).magnitude()
+}
+
+/// Extended Euclid: `(g, x, y)` such that `a * x + b * y == g`, `g` being `gcd(a, b)`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (r0, x0, y0) = (-r0, -x0, -y0);
+    }
+    (r0 as i64, x0 as i64, y0 as i64)
+}
+
+/// `a * b` modulo `modulus`, which must be positive
+pub fn mod_mul(a: i64, b: i64, modulus: i64) -> i64 {
+    assert!(modulus > 0, "non-positive modulus {}", modulus);
+    (a as i128 * b as i128).rem_euclid(modulus as i128) as i64
+}
+
+/// `base` to the power `exp` modulo `modulus`, which must be positive
+pub fn mod_pow(base: i64, exp: u64, modulus: i64) -> i64 {
+    assert!(modulus > 0, "non-positive modulus {}", modulus);
+    let modulus = modulus as i128;
+    let (mut base, mut exp) = ((base as i128).rem_euclid(modulus), exp);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// `x` such that `a * x ≡ 1 (mod modulus)`, `None` when `a` and the modulus are not coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "non-positive modulus {}", modulus);
+    match extended_gcd(a.rem_euclid(modulus), modulus) {
+        (1, x, _) => Some(x.rem_euclid(modulus)),
+        _ => None,
+    }
+}
+
+/// Solve the congruences `x ≡ remainder (mod modulus)`, given as `(remainder, modulus)`
+///
+/// Moduli need not be coprime. The solution is `x ≡ remainder (mod modulus)`,
+/// returned as `(remainder, modulus)`, the modulus being the lcm of all moduli.
+///
+/// # Example
+/// ```
+/// use aoc_utils::math::{crt, CrtError};
+///
+/// assert_eq!(Ok((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+/// assert_eq!(Err(CrtError::Incompatible(1, 4, 0, 6)), crt([(1, 4), (0, 6)]));
+/// ```
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Result<(i64, i64), CrtError> {
+    let (mut remainder, mut modulus) = (0i128, 1i128);
+    for (r, m) in congruences {
+        if m <= 0 {
+            return Err(CrtError::InvalidModulus(m));
+        }
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+        let (g, p, _) = extended_gcd(modulus as i64, m as i64);
+        let g = g as i128;
+        if (r - remainder) % g != 0 {
+            return Err(CrtError::Incompatible(
+                remainder as i64,
+                modulus as i64,
+                r as i64,
+                m as i64,
+            ));
+        }
+        // remainder + modulus * k ≡ r (mod m), with p the inverse of modulus / g modulo m / g
+        let step = m / g;
+        let k = ((r - remainder) / g % step * p as i128).rem_euclid(step);
+        remainder += modulus * k;
+        modulus *= step;
+        if modulus > i64::MAX as i128 {
+            return Err(CrtError::Overflow);
+        }
+    }
+    Ok((remainder as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(6u8, gcd(0, 6));
+        assert_eq!(0, gcd(0i64, 0));
+        assert_eq!(1usize, gcd(17, 5));
+        assert_eq!(36, lcm(12, -18));
+        assert_eq!(0, lcm(0, 7));
+        assert_eq!(2_772u64, lcm(lcm(18, 28), 44));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (46, 0), (0, -5), (i64::MAX, 2)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(gcd(a, b), g);
+            assert_eq!(g as i128, a as i128 * x as i128 + b as i128 * y as i128);
+        }
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 10));
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(1, mod_pow(-2, 2, 3));
+        let big = 119_315_717_514_047;
+        assert_eq!(
+            1,
+            mod_mul(mod_pow(12_345, big as u64 - 2, big), 12_345, big)
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Ok((0, 1)), crt([]));
+        assert_eq!(
+            Ok((1_068_781, 3_162_341)),
+            crt([(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)])
+        );
+        assert_eq!(Ok((10, 12)), crt([(2, 4), (4, 6)]));
+        assert_eq!(Err(CrtError::InvalidModulus(0)), crt([(1, 0)]));
+        assert_eq!(
+            Err(CrtError::Overflow),
+            crt([(0, 4_000_000_007), (0, 4_000_000_009), (0, 4_000_000_037)])
+        );
+    }
+}
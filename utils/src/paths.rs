@@ -0,0 +1,162 @@
+//! Location of puzzle inputs, whatever the working directory
+//!
+//! The inputs of a year are looked up, in order, in:
+//! 1. `$AOC_INPUT_DIR/<year>/`, to keep inputs outside of the repository
+//! 2. `years/<year>/inputs/` of the workspace holding the running crate
+//!    (`CARGO_MANIFEST_DIR`, set by cargo for binaries, tests and benches)
+//! 3. `years/<year>/inputs/` of the workspace `aoc_utils` was built from
+//!
+//! Besides `dayXX.txt`, a day can have alternative inputs named
+//! `dayXX.<variant>.txt`, e.g. `day07.alice.txt` for another account.
+//! Examples stay in `years/<year>/inputs/examples/` of the workspace.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory holding the inputs of every year
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory holding the inputs of a year
+pub fn input_dir(year: u16) -> PathBuf {
+    resolve_input_dir(
+        year,
+        env::var_os(INPUT_DIR_VAR),
+        env::var_os("CARGO_MANIFEST_DIR"),
+    )
+}
+
+/// Input file of a day
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    input_dir(year).join(format!("day{:02}.txt", day))
+}
+
+/// Alternative input file of a day, e.g. `day07.alice.txt`
+pub fn variant_input_path(year: u16, day: u8, variant: &str) -> PathBuf {
+    input_dir(year).join(format!("day{:02}.{}.txt", day, variant))
+}
+
+/// Example `n` of a day, e.g. `inputs/examples/day07_1.txt`
+///
+/// Examples are kept with the year crate, even when `$AOC_INPUT_DIR` is set.
+pub fn example_path(year: u16, day: u8, n: usize) -> PathBuf {
+    resolve_input_dir(year, None, env::var_os("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("day{:02}_{}.txt", day, n))
+}
+
+/// Accepted answers of a year, `years/<year>/answers.toml` of the workspace
+pub fn answers_path(year: u16) -> PathBuf {
+    year_dir(year, env::var_os("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Names of the alternative inputs available for a day, sorted
+pub fn input_variants(year: u16, day: u8) -> Vec<String> {
+    let prefix = format!("day{:02}.", day);
+    let mut variants: Vec<String> = fs::read_dir(input_dir(year))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| {
+            let name = name.strip_suffix(".enc").unwrap_or(&name);
+            let variant = name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            (!variant.is_empty() && !variant.contains('.')).then(|| variant.to_string())
+        })
+        .collect();
+    variants.sort();
+    variants.dedup();
+    variants
+}
+
+/// Year of the running crate, when it is one of the `years/<year>` crates
+pub fn current_year() -> Option<u16> {
+    PathBuf::from(env::var_os("CARGO_MANIFEST_DIR")?)
+        .file_name()?
+        .to_str()?
+        .parse()
+        .ok()
+}
+
+fn resolve_input_dir(
+    year: u16,
+    input_dir: Option<OsString>,
+    manifest_dir: Option<OsString>,
+) -> PathBuf {
+    if let Some(dir) = input_dir.filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir).join(year.to_string());
+    }
+    year_dir(year, manifest_dir).join("inputs")
+}
+
+/// `years/<year>` directory of the workspace holding the running crate, or else `aoc_utils`
+fn year_dir(year: u16, manifest_dir: Option<OsString>) -> PathBuf {
+    let utils_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir
+        .and_then(|dir| find_year_dir(Path::new(&dir), year))
+        .or_else(|| find_year_dir(utils_dir, year))
+        .unwrap_or_else(|| {
+            utils_dir
+                .parent()
+                .unwrap_or(utils_dir)
+                .join("years")
+                .join(year.to_string())
+        })
+}
+
+/// Root of the workspace holding the running crate, or else `aoc_utils`
+pub(crate) fn workspace_dir() -> PathBuf {
+    let utils_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    env::var_os("CARGO_MANIFEST_DIR")
+        .and_then(|dir| {
+            Path::new(&dir)
+                .ancestors()
+                .find(|ancestor| ancestor.join("years").is_dir())
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| utils_dir.parent().unwrap_or(utils_dir).to_path_buf())
+}
+
+/// `years/<year>` directory of the workspace holding `dir`
+fn find_year_dir(dir: &Path, year: u16) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("years").join(year.to_string()))
+        .find(|year_dir| year_dir.is_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace_root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf()
+    }
+
+    #[test]
+    fn test_input_dir_override() {
+        assert_eq!(
+            PathBuf::from("/data/aoc/2019"),
+            resolve_input_dir(2019, Some("/data/aoc".into()), Some("/elsewhere".into()))
+        );
+    }
+
+    #[test]
+    fn test_input_dir_from_any_crate_of_the_workspace() {
+        let expected = workspace_root().join("years").join("2019").join("inputs");
+
+        for manifest_dir in [
+            workspace_root().join("years").join("2019"),
+            workspace_root().join("years").join("2024"),
+            workspace_root().join("runner"),
+        ] {
+            assert_eq!(
+                expected,
+                resolve_input_dir(2019, None, Some(manifest_dir.into_os_string()))
+            );
+        }
+        assert_eq!(expected, resolve_input_dir(2019, Some("".into()), None));
+    }
+}
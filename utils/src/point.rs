@@ -0,0 +1,179 @@
+//! Points of the plane and of space
+//!
+//! Grids are indexed by [`Point2`], `x` growing rightwards and `y` downwards
+//! from their top-left cell. Which way `y` grows only matters when moving in a
+//! direction, see [`YAxis`](crate::direction::YAxis).
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point, or vector, of the plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    /// Taxicab distance, moving along the axes only
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Chessboard distance, moving diagonally as well
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Point, or vector, of space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// Taxicab distance, moving along the axes only
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Distance moving diagonally as well, like a king in 3D chess
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Component-wise arithmetic of a point type
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point2::new(1, 2);
+        p += Point2::new(3, -4);
+
+        assert_eq!(Point2::new(4, -2), p);
+        assert_eq!(Point2::new(-2, 6), Point2::new(1, 2) - Point2::new(3, -4));
+        assert_eq!(Point2::new(-3, 6), -Point2::new(1, -2) * 3);
+        assert_eq!(Point2::new(5, 7), Point2::from((5, 7)));
+        assert_eq!("(-1, 2)", Point2::new(-1, 2).to_string());
+
+        let mut q = Point3::new(1, 2, 3);
+        q -= Point3::new(1, 1, 1);
+        assert_eq!(Point3::new(0, 2, 4), q * 2 - Point3::ORIGIN);
+        assert_eq!("(0, -1, -2)", (-q).to_string());
+    }
+
+    #[test]
+    fn test_distances() {
+        let p = Point2::new(3, -4);
+
+        assert_eq!(7, p.manhattan(Point2::ORIGIN));
+        assert_eq!(4, p.chebyshev(Point2::ORIGIN));
+        assert_eq!(
+            p.manhattan(Point2::new(1, 1)),
+            Point2::new(1, 1).manhattan(p)
+        );
+        assert_eq!(
+            (9, 4),
+            (
+                Point3::new(1, -4, 2).manhattan(Point3::new(0, 0, -2)),
+                Point3::new(1, -4, 2).chebyshev(Point3::new(0, 0, -2))
+            )
+        );
+    }
+}
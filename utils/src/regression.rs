@@ -0,0 +1,166 @@
+//! Regression tests of the solutions on the real inputs
+//!
+//! Year crates generate a `dayXX_input` test for every day of their
+//! `answers.toml` from their build script with [`write_input_tests`]. Inputs
+//! are personal and usually not committed: the test of a day whose input is
+//! absent is ignored, its reason naming the missing file, so that a checkout
+//! without inputs still passes. So is the test of an encrypted input without
+//! the key to decrypt it, see [`crypt`](crate::crypt).
+
+use crate::crypt::{self, input_exists, read_input_file, CryptError, Key, KEY_FILE_VAR};
+use crate::fixture::rerun_if_changed;
+use crate::paths::{answers_path, current_year, input_dir, input_path, INPUT_DIR_VAR};
+use crate::{AocInputError, KnownAnswers, Part, Registry, Verdict};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Check a day against the known answers of its year on the real input, panicking on mismatch
+///
+/// The input is only skipped with a message when it is absent, or encrypted
+/// without a key, e.g. when ignored tests are run anyway. Called by the tests
+/// generated by [`write_input_tests`].
+pub fn check_input(register: fn(&mut Registry), year: u16, day: u8) {
+    let path = input_path(year, day);
+    if !input_exists(&path) {
+        eprintln!(
+            "{} day {:02} skipped: no input `{}`",
+            year,
+            day,
+            path.display()
+        );
+        return;
+    }
+    let input = match read_input_file(&path) {
+        Err(AocInputError::Decrypt(e @ CryptError::NoKey(_))) => {
+            eprintln!("{} day {:02} skipped: {}", year, day, e);
+            return;
+        }
+        result => result.unwrap_or_else(|e| panic!("{}", e)),
+    };
+
+    let mut registry = Registry::new();
+    register(&mut registry);
+    let solution = registry
+        .get(year, day)
+        .unwrap_or_else(|| panic!("no solution registered for {} day {:02}", year, day));
+    let answers = KnownAnswers::load(&answers_path(year)).unwrap_or_else(|e| panic!("{}", e));
+    let parsed = solution
+        .parse(&input)
+        .unwrap_or_else(|e| panic!("{} day {:02}: cannot parse input: {}", year, day, e));
+
+    let mut mismatches = Vec::new();
+    for part in Part::ALL {
+        if answers.get(day, part).is_none() {
+            continue;
+        }
+        match solution.solve(part, parsed.as_ref()) {
+            Ok(answer) => {
+                if let Verdict::Fail { expected } = answers.check(day, part, &answer) {
+                    mismatches.push(format!(
+                        "part {}: expected `{}`, got `{}`",
+                        part, expected, answer
+                    ));
+                }
+            }
+            Err(e) => mismatches.push(format!("part {}: {}", part, e)),
+        }
+    }
+    if !mismatches.is_empty() {
+        panic!("{} day {:02}:\n{}", year, day, mismatches.join("\n"));
+    }
+}
+
+/// Source of one test per day of `answers`, ignored when its input is not in `input_dir`,
+/// or only encrypted while there is no key
+pub fn input_tests(year: u16, answers: &KnownAnswers, input_dir: &Path, has_key: bool) -> String {
+    let mut tests = String::from(
+        "// Generated by `aoc_utils::regression::write_input_tests` from `answers.toml`\n",
+    );
+    for day in answers.days() {
+        let path = input_dir.join(format!("day{:02}.txt", day));
+
+        let reason = match path.is_file() {
+            true => None,
+            false if !input_exists(&path) => Some(format!("no input {}", path.display())),
+            false if !has_key => Some(format!("no key to decrypt {}.enc", path.display())),
+            false => None,
+        };
+
+        tests.push_str("\n#[test]\n");
+        if let Some(reason) = reason {
+            tests.push_str(&format!("#[ignore = {:?}]\n", reason));
+        }
+        tests.push_str(&format!(
+            "fn day{day:02}_input() {{\n    \
+             ::aoc_utils::regression::check_input(crate::register, {year}, {day});\n}}\n"
+        ));
+    }
+    tests
+}
+
+/// Write `input_tests.rs` into `OUT_DIR`, for the build script of a year crate
+///
+/// The year crate includes it in its `lib.rs`:
+/// ```ignore
+/// #[cfg(test)]
+/// mod input_tests {
+///     include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+/// }
+/// ```
+pub fn write_input_tests() {
+    let year = current_year().expect("year crates are in a `years/<year>` directory");
+    let answers_path = answers_path(year);
+    let answers = KnownAnswers::load(&answers_path).unwrap_or_else(|e| panic!("{}", e));
+    let input_dir = input_dir(year);
+    let has_key = Key::from_env().is_ok();
+
+    println!("cargo:rerun-if-env-changed={}", INPUT_DIR_VAR);
+    println!("cargo:rerun-if-env-changed={}", KEY_FILE_VAR);
+    rerun_if_changed(&answers_path);
+    rerun_if_changed(&input_dir);
+    rerun_if_changed(&crypt::key_file());
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("run by cargo"));
+    fs::write(
+        out_dir.join("input_tests.rs"),
+        input_tests(year, &answers, &input_dir, has_key),
+    )
+    .expect("cannot write input tests");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_tests() {
+        let dir = env::temp_dir().join(format!("aoc_regression_inputs_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07.txt"), "3,0,4,0,99\n").unwrap();
+        fs::write(dir.join("day09.txt.enc"), "").unwrap();
+        let answers = KnownAnswers::from_toml(
+            "[day07]\npart1 = 1\n\n[day08]\npart2 = \"ABC\"\n\n[day09]\npart1 = 2\n",
+        )
+        .unwrap();
+
+        let tests = input_tests(2019, &answers, &dir, false);
+        let with_key = input_tests(2019, &answers, &dir, true);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(tests.contains(
+            "#[test]\nfn day07_input() {\n    \
+             ::aoc_utils::regression::check_input(crate::register, 2019, 7);\n}\n"
+        ));
+        assert!(tests.contains(&format!(
+            "#[test]\n#[ignore = {:?}]\nfn day08_input() {{",
+            format!("no input {}", dir.join("day08.txt").display())
+        )));
+        assert!(tests.contains(&format!(
+            "#[test]\n#[ignore = {:?}]\nfn day09_input() {{",
+            format!("no key to decrypt {}.enc", dir.join("day09.txt").display())
+        )));
+        assert!(with_key.contains("#[test]\nfn day09_input() {"));
+        assert!(!tests.contains("day01_input"));
+    }
+}
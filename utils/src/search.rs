@@ -0,0 +1,401 @@
+//! Graph searches: breadth-first, depth-first, Dijkstra and A*
+//!
+//! Graphs are never built: a search is given its start nodes and a closure
+//! listing the neighbours of a node, so nodes can be anything hashable, from
+//! the keys of an adjacency map to whole puzzle states. Searches stop at the
+//! first node matching their goal predicate, `|_| false` exploring every
+//! reachable node, and return a [`Search`] holding the distance and parent of
+//! every node reached, from which paths are rebuilt.
+//!
+//! # Example
+//! ```
+//! use aoc_utils::search::bfs;
+//! use std::collections::HashMap;
+//!
+//! let graph = HashMap::from([("a", vec!["b", "c"]), ("b", vec!["d"]), ("c", vec!["d"])]);
+//! let search = bfs(["a"], |n| graph.get(n).into_iter().flatten().copied(), |_| false);
+//!
+//! assert_eq!(Some(2), search.distance(&"d"));
+//! assert_eq!(Some(vec!["a", "b", "d"]), search.path(&"d"));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Nodes reached by a search, with their distance from the nearest start and their parent
+#[derive(Debug, Clone)]
+pub struct Search<N, C = usize> {
+    reached: HashMap<N, (C, Option<N>)>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Search<N, C> {
+        Search {
+            reached: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Distance from the nearest start to `node`, `None` when it was not reached
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|&(distance, _)| distance)
+    }
+
+    /// Node before `node` on its path, `None` for starts and unreached nodes
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.reached.get(node)?.1.as_ref()
+    }
+
+    /// Nodes from a start to `node`, both included
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut parent = self.reached.get(node)?.1.as_ref();
+        while let Some(node) = parent {
+            path.push(node.clone());
+            parent = self.parent(node);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    /// First node found matching the goal, `None` when the search ran out of nodes
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Nodes reached with their distance, in no particular order
+    pub fn distances(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached
+            .iter()
+            .map(|(node, &(distance, _))| (node, distance))
+    }
+
+    /// Number of nodes reached, starts included
+    pub fn len(&self) -> usize {
+        self.reached.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reached.is_empty()
+    }
+}
+
+/// Breadth-first search, distances counting edges
+///
+/// Every node reached is at its shortest distance from the starts.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.contains(&start) {
+            search.reached.insert(start.clone(), (0, None));
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for next in neighbours(&node) {
+            if !search.contains(&next) {
+                search
+                    .reached
+                    .insert(next.clone(), (distance + 1, Some(node.clone())));
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Depth-first search, distances being the depth of nodes in the search tree
+///
+/// Neighbours are explored in the order they are listed. Distances are those
+/// of the paths found, not necessarily the shortest ones.
+pub fn dfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut stack: Vec<(N, usize, Option<N>)> = Vec::new();
+    let mut starts = starts.into_iter().collect::<Vec<N>>();
+    starts.reverse();
+    stack.extend(starts.into_iter().map(|start| (start, 0, None)));
+
+    while let Some((node, depth, parent)) = stack.pop() {
+        if search.contains(&node) {
+            continue;
+        }
+        search.reached.insert(node.clone(), (depth, parent));
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let first = stack.len();
+        for next in neighbours(&node) {
+            if !search.contains(&next) {
+                stack.push((next, depth + 1, Some(node.clone())));
+            }
+        }
+        stack[first..].reverse();
+    }
+    search
+}
+
+/// Dijkstra's search over edges of non-negative costs, listed along with each neighbour
+///
+/// Costs can be any integer type, `C::default()` being zero. Only the nodes
+/// settled before reaching the goal are kept, at their shortest distance.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), goal)
+}
+
+/// A* search, guided by a `heuristic` estimating the cost left to reach the goal
+///
+/// The heuristic must be consistent: never more than the cost of an edge plus
+/// its estimate from the other end, and zero on goals. The Manhattan distance
+/// to a target is one for unit moves on a grid.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // best distance found so far of the nodes not settled yet
+    let mut frontier: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        frontier.insert(start.clone(), (C::default(), None));
+        queue.push(Queued {
+            estimate,
+            distance: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Queued { distance, node, .. }) = queue.pop() {
+        match frontier.get(&node) {
+            Some(&(best, _)) if best == distance => {}
+            // settled already, or queued again since with a shorter distance
+            _ => continue,
+        }
+        let settled = frontier.remove(&node).unwrap();
+        search.reached.insert(node.clone(), settled);
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, cost) in neighbours(&node) {
+            if search.contains(&next) {
+                continue;
+            }
+            let next_distance = distance + cost;
+            if frontier
+                .get(&next)
+                .is_some_and(|&(best, _)| best <= next_distance)
+            {
+                continue;
+            }
+            frontier.insert(next.clone(), (next_distance, Some(node.clone())));
+            queue.push(Queued {
+                estimate: next_distance + heuristic(&next),
+                distance: next_distance,
+                node: next,
+            });
+        }
+    }
+    search
+}
+
+/// Node waiting in the queue of [`astar`], the lowest estimate coming out first
+struct Queued<N, C> {
+    estimate: C,
+    distance: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed for a min-heap, preferring the nodes further along on ties
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.distance.cmp(&other.distance))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::{Direction4, YAxis};
+    use crate::grid::Grid;
+    use crate::point::Point2;
+
+    fn graph() -> HashMap<char, Vec<(char, u32)>> {
+        HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+            ('g', vec![]),
+        ])
+    }
+
+    fn unweighted(graph: &HashMap<char, Vec<(char, u32)>>, n: &char) -> Vec<char> {
+        graph[n].iter().map(|&(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = graph();
+        let search = bfs(['a'], |n| unweighted(&graph, n), |_| false);
+
+        assert_eq!(6, search.len());
+        assert_eq!(Some(2), search.distance(&'e'));
+        assert_eq!(Some(vec!['a', 'f', 'e']), search.path(&'e'));
+        assert_eq!(Some(&'a'), search.parent(&'b'));
+        assert_eq!((None, None), (search.distance(&'g'), search.path(&'g')));
+        assert_eq!(None, search.goal());
+    }
+
+    #[test]
+    fn test_bfs_goal_and_starts() {
+        let graph = graph();
+        let search = bfs(['e', 'b'], |n| unweighted(&graph, n), |&n| n == 'c');
+
+        assert_eq!(Some(&'c'), search.goal());
+        assert_eq!(Some(1), search.goal_distance());
+        assert_eq!(Some(vec!['b', 'c']), search.goal_path());
+        assert_eq!(Some(0), search.distance(&'e'));
+
+        let search = bfs(['g', 'c'], |n| unweighted(&graph, n), |&n| n == 'g');
+        assert_eq!(Some(vec!['g']), search.goal_path());
+    }
+
+    #[test]
+    fn test_dfs() {
+        let graph = graph();
+        let search = dfs(['a'], |n| unweighted(&graph, n), |&n| n == 'e');
+
+        assert_eq!(Some(vec!['a', 'b', 'c', 'd', 'e']), search.goal_path());
+        assert_eq!(Some(4), search.goal_distance());
+        assert!(!search.contains(&'f'));
+        assert_eq!(6, dfs(['a'], |n| unweighted(&graph, n), |_| false).len());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = graph();
+        let search = dijkstra(['a'], |n| graph[n].clone(), |&n| n == 'e');
+
+        assert_eq!(Some(20), search.goal_distance());
+        assert_eq!(Some(vec!['a', 'c', 'f', 'e']), search.goal_path());
+        assert_eq!(Some(11), search.distance(&'f'));
+
+        let search = dijkstra(['b', 'e'], |n| graph[n].clone(), |_| false);
+        assert_eq!(Some(6), search.distance(&'d'));
+        assert_eq!(Some(9), search.distance(&'f'));
+        assert_eq!(Some(vec!['b', 'c']), search.path(&'c'));
+        assert_eq!(Some(vec!['b', 'a']), search.path(&'a'));
+    }
+
+    #[test]
+    fn test_grid_states() {
+        let maze = Grid::parse("S.#.\n.##.\n...E\n", Some).unwrap();
+        let start = maze.position(|&c| c == 'S').unwrap();
+        let end = maze.position(|&c| c == 'E').unwrap();
+        let open = |p: Point2| maze.get(p).is_some_and(|&c| c != '#');
+        let moves = |&p: &Point2| {
+            maze.neighbours4(p)
+                .filter(|&q| open(q))
+                .map(|q| (q, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let search = astar([start], moves, |p| p.manhattan(end), |&p| p == end);
+        assert_eq!(Some(5), search.goal_distance());
+        assert_eq!(Some(6), search.goal_path().map(|path| path.len()));
+        let explored = search.len();
+        let search = dijkstra([start], moves, |&p| p == end);
+        assert_eq!(Some(5), search.goal_distance());
+        assert!(explored <= search.len());
+
+        // states of a robot which turns on the spot or steps ahead
+        let search = bfs(
+            [(start, Direction4::East)],
+            |&(p, d)| {
+                let ahead = p + d.offset(YAxis::Down);
+                let mut next = vec![(p, d.left()), (p, d.right())];
+                if open(ahead) {
+                    next.push((ahead, d));
+                }
+                next
+            },
+            |&(p, _)| p == end,
+        );
+        assert_eq!(Some(7), search.goal_distance());
+        assert_eq!(Some(&(end, Direction4::East)), search.goal());
+    }
+}
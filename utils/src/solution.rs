@@ -0,0 +1,449 @@
+//! Common interface implemented by every puzzle solution
+//!
+//! Each year crate implements [`Solution`] once per day and exposes a
+//! `register` function adding its days to a [`Registry`]. Tooling can then
+//! enumerate and drive every puzzle without knowing its concrete types.
+
+use crate::generate::{Generator, InputGenerator};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Error returned by a solution while parsing its input or solving a part
+pub type SolutionError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Typed answer produced by a puzzle part
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    /// The part has not been solved yet
+    Unsolved,
+}
+
+impl Answer {
+    /// Name of the answer type, as shown in reports
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::UInt(_) => "uint",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    /// Whether the answer is the expected one, as displayed
+    ///
+    /// Leading and trailing newlines of rendered answers are not significant.
+    pub fn matches(&self, expected: &str) -> bool {
+        self.to_string().trim_matches('\n') == expected.trim_matches('\n')
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::UInt(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(Int, i64, i8, i16, i32, i64, isize);
+impl_answer_from!(UInt, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Puzzle part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("unknown part: `{}`", value)),
+        }
+    }
+}
+
+/// Puzzle parameters of an input, e.g. the number of steps, which examples
+/// often set lower than the real input
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn insert(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+
+    /// Value of a parameter, `None` when it is not set
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, SolutionError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.0
+            .get(name)
+            .map(|value| {
+                value
+                    .parse::<T>()
+                    .map_err(|e| format!("invalid parameter `{}`: {}", name, e).into())
+            })
+            .transpose()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// Solution of a single day
+///
+/// The input is parsed once and shared by both parts.
+///
+/// # Example
+/// ```ignore
+/// pub struct Day01;
+///
+/// impl Solution for Day01 {
+///     const YEAR: u16 = 2024;
+///     const DAY: u8 = 1;
+///     type Input = Vec<i32>;
+///
+///     fn parse(input: &str) -> Result<Self::Input, SolutionError> { ... }
+///     fn part1(input: &Self::Input) -> Result<Answer, SolutionError> { ... }
+///     fn part2(input: &Self::Input) -> Result<Answer, SolutionError> { ... }
+/// }
+/// ```
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Parsed puzzle input
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError>;
+
+    /// Parse an input given with puzzle parameters, e.g. by an example fixture
+    ///
+    /// Only days whose examples use other parameters than the real input need
+    /// to override it, the parameters being ignored by default.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, SolutionError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// Type-erased [`Solution`], as stored in a [`Registry`]
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, SolutionError>;
+
+    /// Parse an input with puzzle parameters, see [`Solution::parse_with`]
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Box<dyn Any>, SolutionError>;
+
+    /// Solve a part from the value returned by [`DynSolution::parse`]
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Result<Answer, SolutionError>;
+
+    /// Parse the input and solve both parts
+    fn run(&self, input: &str) -> Result<(Answer, Answer), SolutionError> {
+        let parsed = self.parse(input)?;
+        Ok((
+            self.solve(Part::One, parsed.as_ref())?,
+            self.solve(Part::Two, parsed.as_ref())?,
+        ))
+    }
+}
+
+struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S> DynSolution for Registered<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, SolutionError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Box<dyn Any>, SolutionError> {
+        Ok(Box::new(S::parse_with(input, params)?))
+    }
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Result<Answer, SolutionError> {
+        let input = parsed.downcast_ref::<S::Input>().ok_or_else(|| {
+            format!(
+                "parsed input does not belong to {} day {:02}",
+                S::YEAR,
+                S::DAY
+            )
+        })?;
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+}
+
+/// Solutions keyed by (year, day), with the input generators of some days
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Box<dyn DynSolution>>,
+    generators: BTreeMap<(u16, u8), InputGenerator>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Register a solution, replacing any previous one for the same day
+    pub fn register<S>(&mut self) -> &mut Registry
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        self.solutions
+            .insert((S::YEAR, S::DAY), Box::new(Registered::<S>(PhantomData)));
+        self
+    }
+
+    /// Register the input generator of a day, replacing any previous one
+    pub fn register_generator<G>(&mut self) -> &mut Registry
+    where
+        G: Generator + 'static,
+    {
+        self.generators
+            .insert((G::YEAR, G::DAY), InputGenerator::new::<G>());
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DynSolution> {
+        self.solutions.get(&(year, day)).map(|s| s.as_ref())
+    }
+
+    /// Registered years, in ascending order
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.keys().map(|&(year, _)| year).collect();
+        years.dedup();
+        years
+    }
+
+    /// Solutions of a year, ordered by day
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|(_, s)| s.as_ref())
+    }
+
+    /// All solutions, ordered by year then day
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.values().map(|s| s.as_ref())
+    }
+
+    pub fn generator(&self, year: u16, day: u8) -> Option<&InputGenerator> {
+        self.generators.get(&(year, day))
+    }
+
+    /// Input generators, ordered by year then day
+    pub fn generators(&self) -> impl Iterator<Item = &InputGenerator> {
+        self.generators.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Ok(input
+                .lines()
+                .map(|l| l.parse::<i32>())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+            Ok(input.iter().sum::<i32>().into())
+        }
+    }
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        type Input = String;
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+            Ok(input.as_str().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn test_registry_lookup_and_order() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>().register::<Echo>();
+
+        assert_eq!(2, registry.len());
+        assert_eq!(vec![2000], registry.years());
+        assert_eq!(
+            vec![1, 2],
+            registry.year(2000).map(|s| s.day()).collect::<Vec<u8>>()
+        );
+        assert!(registry.get(2000, 3).is_none());
+        assert!(registry.year(2001).next().is_none());
+    }
+
+    #[test]
+    fn test_run_registered_solution() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>().register::<Echo>();
+
+        assert_eq!(
+            (Answer::Int(6), Answer::Unsolved),
+            registry.get(2000, 2).unwrap().run("1\n2\n3").unwrap()
+        );
+        assert_eq!(
+            (Answer::Text("abc".to_string()), Answer::UInt(3)),
+            registry.get(2000, 1).unwrap().run("abc").unwrap()
+        );
+        assert!(registry.get(2000, 2).unwrap().run("1\nx").is_err());
+    }
+
+    #[test]
+    fn test_solve_with_foreign_input() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>().register::<Echo>();
+
+        let parsed = registry.get(2000, 1).unwrap().parse("abc").unwrap();
+        assert!(registry
+            .get(2000, 2)
+            .unwrap()
+            .solve(Part::One, parsed.as_ref())
+            .is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new();
+        params.insert("steps", "10");
+
+        assert_eq!(Some(10), params.get::<u32>("steps").unwrap());
+        assert_eq!(None, params.get::<u32>("size").unwrap());
+        params.insert("steps", "ten");
+        assert!(params.get::<u32>("steps").is_err());
+    }
+
+    #[test]
+    fn test_answer_matches() {
+        assert!(Answer::Int(-3).matches("-3"));
+        assert!(Answer::Text("\n#.\n.#".to_string()).matches("#.\n.#\n"));
+        assert!(Answer::Text("\nABC".to_string()).matches("ABC"));
+        assert!(!Answer::Text(" ABC".to_string()).matches("ABC"));
+        assert!(!Answer::Unsolved.matches("1"));
+    }
+}
@@ -0,0 +1,257 @@
+//! Unbounded grids, storing only the cells which have been set
+//!
+//! Like [`Grid`], cells are addressed by [`Point2`] with `y` growing downwards,
+//! but coordinates can be negative. Unset cells read as the default value of
+//! the grid, and the bounding box of the set cells is kept up to date.
+
+use crate::grid::Grid;
+use crate::point::Point2;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Smallest rectangle holding a set of points, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// Top-left corner
+    pub min: Point2,
+    /// Bottom-right corner
+    pub max: Point2,
+}
+
+impl Bounds {
+    /// Bounds of a single point
+    pub fn of(p: Point2) -> Bounds {
+        Bounds { min: p, max: p }
+    }
+
+    /// Grow the bounds to hold `p`
+    pub fn include(&mut self, p: Point2) {
+        self.min = Point2::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point2::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// Whether `p` is on the edge of the bounds, where removing it could shrink them
+    fn on_edge(&self, p: Point2) -> bool {
+        p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+}
+
+/// Grid of the plane holding a value for every point, most of them the default one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Empty grid, every cell reading as `default`
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Value of the cell at `p`, the default one when it has not been set
+    pub fn get(&self, p: Point2) -> &T {
+        self.cells.get(&p).unwrap_or(&self.default)
+    }
+
+    pub fn is_set(&self, p: Point2) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Set the cell at `p`, returning its previous value if it was set
+    pub fn set(&mut self, p: Point2, value: T) -> Option<T> {
+        self.include(p);
+        self.cells.insert(p, value)
+    }
+
+    /// Unset the cell at `p`, returning its value if it was set
+    pub fn remove(&mut self, p: Point2) -> Option<T> {
+        let value = self.cells.remove(&p)?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(p)) {
+            self.bounds = None;
+            for &q in self.cells.keys() {
+                self.bounds.get_or_insert(Bounds::of(q)).include(q);
+            }
+        }
+        Some(value)
+    }
+
+    fn include(&mut self, p: Point2) {
+        match self.bounds.as_mut() {
+            Some(bounds) => bounds.include(p),
+            None => self.bounds = Some(Bounds::of(p)),
+        }
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Bounding box of the set cells, `None` when there is none
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Number of set cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Set cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells.iter().map(|(&p, value)| (p, value))
+    }
+
+    /// Positions of the set cells, in no particular order
+    pub fn points(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.cells.keys().copied()
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Mutable value of the cell at `p`, which is set to the default value first if needed
+    pub fn get_mut(&mut self, p: Point2) -> &mut T {
+        self.include(p);
+        self.cells.entry(p).or_insert_with(|| self.default.clone())
+    }
+
+    /// Dense copy of the bounding box, and the position of its top-left cell
+    pub fn to_grid(&self) -> (Point2, Grid<T>) {
+        match self.bounds {
+            Some(bounds) => (
+                bounds.min,
+                Grid::from_fn(bounds.width(), bounds.height(), |p| {
+                    self.get(bounds.min + p).clone()
+                }),
+            ),
+            None => (
+                Point2::ORIGIN,
+                Grid::from_fn(0, 0, |_| self.default.clone()),
+            ),
+        }
+    }
+
+    /// Text of the bounding box, each cell being drawn by `render`
+    ///
+    /// # Example
+    /// ```
+    /// use aoc_utils::{Point2, SparseGrid};
+    ///
+    /// let mut hull = SparseGrid::new(false);
+    /// hull.set(Point2::new(-1, 0), true);
+    /// hull.set(Point2::new(1, 1), true);
+    /// assert_eq!("#..\n..#", hull.render(|&white| if white { '#' } else { '.' }));
+    /// ```
+    pub fn render<D: fmt::Display>(&self, render: impl Fn(&T) -> D) -> String {
+        self.to_grid().1.display(render).to_string()
+    }
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Sparse copy of `grid`, placed with its top-left cell at `origin`
+    ///
+    /// Only the cells differing from `default` are set.
+    pub fn from_grid(grid: &Grid<T>, origin: Point2, default: T) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new(default);
+        for (p, value) in grid.iter() {
+            if *value != sparse.default {
+                sparse.set(origin + p, value.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = SparseGrid::new('.');
+
+        assert_eq!(&'.', grid.get(Point2::new(-5, 3)));
+        assert_eq!(None, grid.set(Point2::new(-5, 3), '#'));
+        assert_eq!(Some('#'), grid.set(Point2::new(-5, 3), '@'));
+        *grid.get_mut(Point2::new(2, -1)) = '#';
+
+        assert_eq!(&'@', grid.get(Point2::new(-5, 3)));
+        assert!(grid.is_set(Point2::new(2, -1)) && !grid.is_set(Point2::ORIGIN));
+        assert_eq!(2, grid.len());
+        assert_eq!(
+            vec![(Point2::new(2, -1), &'#')],
+            grid.iter().filter(|(_, &c)| c == '#').collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new(0);
+        assert_eq!(None, grid.bounds());
+
+        grid.set(Point2::new(1, 1), 1);
+        grid.set(Point2::new(-2, 3), 2);
+        grid.set(Point2::new(0, -1), 3);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (Point2::new(-2, -1), Point2::new(1, 3)),
+            (bounds.min, bounds.max)
+        );
+        assert_eq!((4, 5), (bounds.width(), bounds.height()));
+        assert!(bounds.contains(Point2::ORIGIN) && !bounds.contains(Point2::new(2, 0)));
+
+        grid.remove(Point2::new(-2, 3));
+        assert_eq!(
+            Some(Bounds {
+                min: Point2::new(0, -1),
+                max: Point2::new(1, 1)
+            }),
+            grid.bounds()
+        );
+        grid.remove(Point2::new(1, 1));
+        grid.remove(Point2::new(0, -1));
+        assert_eq!(None, grid.bounds());
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_grid_conversions() {
+        let dense = Grid::parse("#..\n.#.\n", Some).unwrap();
+        let sparse = SparseGrid::from_grid(&dense, Point2::new(-1, -1), '.');
+
+        assert_eq!(2, sparse.len());
+        assert_eq!(&'#', sparse.get(Point2::ORIGIN));
+        let (origin, grid) = sparse.to_grid();
+        assert_eq!(Point2::new(-1, -1), origin);
+        assert_eq!("#.\n.#", grid.to_string());
+        assert_eq!("#.\n.#", sparse.render(|&c| c));
+        assert_eq!("", SparseGrid::<char>::default().render(|&c| c));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use aoc_2018::day01::{compute_frequency, find_first_frequency_reached_twice};
 use std::io::{self, Error, Read, Write};
 use std::process;
 
@@ -30,42 +30,3 @@ fn main() -> Result<(), Error> {
 
     Ok(())
 }
-
-fn compute_frequency(input: &str) -> Result<i32, std::io::Error> {
-    let mut frequency: i32 = 0;
-    for line in input.lines() {
-        match line.parse::<i32>() {
-            Ok(frequency_change) => {
-                frequency += frequency_change;
-            }
-            Err(e) => {
-                eprintln!("error parsing frequency change `{}`: {}", line, e);
-            }
-        }
-    }
-    Ok(frequency)
-}
-
-fn find_first_frequency_reached_twice(input: &str) -> Result<i32, std::io::Error> {
-    let mut frequency: i32 = 0;
-    let mut frequencies = HashSet::new();
-    frequencies.insert(frequency);
-    loop {
-        // TODO: Find a better way than opening multiple times the file
-        // something like cycle over iterator
-        for line in input.lines() {
-            match line.parse::<i32>() {
-                Ok(frequency_change) => {
-                    frequency += frequency_change;
-                    if frequencies.contains(&frequency) {
-                        return Ok(frequency);
-                    }
-                    frequencies.insert(frequency);
-                }
-                Err(e) => {
-                    eprintln!("error parsing frequency change `{}`: {}", line, e);
-                }
-            }
-        }
-    }
-}
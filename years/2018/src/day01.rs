@@ -0,0 +1,61 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use std::collections::HashSet;
+
+pub fn compute_frequency(input: &str) -> Result<i32, std::io::Error> {
+    let mut frequency: i32 = 0;
+    for line in input.lines() {
+        match line.parse::<i32>() {
+            Ok(frequency_change) => {
+                frequency += frequency_change;
+            }
+            Err(e) => {
+                eprintln!("error parsing frequency change `{}`: {}", line, e);
+            }
+        }
+    }
+    Ok(frequency)
+}
+
+pub fn find_first_frequency_reached_twice(input: &str) -> Result<i32, std::io::Error> {
+    let mut frequency: i32 = 0;
+    let mut frequencies = HashSet::new();
+    frequencies.insert(frequency);
+    loop {
+        // TODO: Find a better way than opening multiple times the file
+        // something like cycle over iterator
+        for line in input.lines() {
+            match line.parse::<i32>() {
+                Ok(frequency_change) => {
+                    frequency += frequency_change;
+                    if frequencies.contains(&frequency) {
+                        return Ok(frequency);
+                    }
+                    frequencies.insert(frequency);
+                }
+                Err(e) => {
+                    eprintln!("error parsing frequency change `{}`: {}", line, e);
+                }
+            }
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 1;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(compute_frequency(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(find_first_frequency_reached_twice(input)?.into())
+    }
+}
@@ -0,0 +1,50 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::vec::Vec;
+
+#[derive(Debug)]
+pub struct Claim {
+    pub id: String,
+    pub position_x: u32,
+    pub position_y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn get_claims(input: &str) -> Vec<Claim> {
+    let mut claims: Vec<Claim> = Vec::new();
+
+    for line in input.lines() {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(?P<id>[\S]+) @ (?P<position_x>[\d]+),(?P<position_y>[\d]+): (?P<width>[\d]+)x(?P<height>[\d]+)").unwrap();
+        }
+        if let Some(caps) = RE.captures(line) {
+            let claim = Claim {
+                id: caps["id"].parse().unwrap(),
+                position_x: caps["position_x"].parse().unwrap(),
+                position_y: caps["position_y"].parse().unwrap(),
+                width: caps["width"].parse().unwrap(),
+                height: caps["height"].parse().unwrap(),
+            };
+            claims.push(claim);
+        }
+    }
+    claims
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 3;
+    type Input = Vec<Claim>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_claims(input))
+    }
+
+    fn part1(_claims: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(Answer::Unsolved)
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_utils::bench::bench_registry;
+use aoc_utils::Registry;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_2019(c: &mut Criterion) {
+    let mut registry = Registry::new();
+    aoc_2019::register(&mut registry);
+    bench_registry(c, &registry);
+}
+
+criterion_group!(benches, bench_2019);
+criterion_main!(benches);
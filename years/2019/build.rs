@@ -0,0 +1,4 @@
+fn main() {
+    aoc_utils::fixture::write_example_tests();
+    aoc_utils::regression::write_input_tests();
+}
@@ -1,7 +1,7 @@
+use aoc_2019::day02::get_instructions;
 use intcode::Vm;
 use std::collections::VecDeque;
 use std::io::{self, Error, Read, Write};
-use std::vec::Vec;
 
 fn main() -> Result<(), Error> {
     let mut input = String::new();
@@ -37,16 +37,3 @@ fn main() -> Result<(), Error> {
 
     Ok(())
 }
-
-fn get_instructions(input: &mut String, noun: i64, verb: i64) -> Vec<i64> {
-    input.retain(|c| !c.is_whitespace());
-    let mut instructions: Vec<i64> = input
-        .split(',')
-        .flat_map(|e| e.parse::<i64>())
-        .collect::<Vec<i64>>();
-
-    instructions[1] = noun;
-    instructions[2] = verb;
-
-    instructions
-}
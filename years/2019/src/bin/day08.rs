@@ -1,3 +1,4 @@
+use aoc_2019::day08::{build_image, count_in_layer, find_layer_having_fewest, merge_layers, Image};
 use std::io::{self, Error, Read, Write};
 
 fn main() -> Result<(), Error> {
@@ -28,95 +29,3 @@ fn main() -> Result<(), Error> {
 
     Ok(())
 }
-
-fn count_in_layer(input: i32, layer: usize, image: &Image) -> i32 {
-    let mut input_count: i32 = 0;
-    let pixels_in_layer: usize = image.width * image.height;
-    let start = layer * pixels_in_layer;
-    let stop = start + pixels_in_layer;
-
-    for p in start..stop {
-        if image.pixels[p] == input {
-            input_count += 1;
-        }
-    }
-
-    input_count
-}
-
-fn find_layer_having_fewest(input: i32, image: &Image) -> Option<(usize, i32)> {
-    let mut min_layer: Option<(usize, i32)> = None;
-    let mut current_layer_input_count: i32;
-
-    for current_layer in 0..image.layers_count {
-        current_layer_input_count = count_in_layer(input, current_layer, image);
-        if min_layer.is_none() || current_layer_input_count < min_layer.unwrap().1 {
-            min_layer = Some((current_layer, current_layer_input_count));
-        }
-    }
-
-    min_layer
-}
-
-fn merge_layers(image: &Image) -> Image {
-    let mut result_pixels: Vec<i32> = vec![0; image.width * image.height];
-
-    for i in 0..image.width * image.height {
-        result_pixels[i] = get_pixel_value(i, image).unwrap();
-    }
-
-    build_image(&result_pixels, image.width, image.height)
-}
-
-fn get_pixel_value(position: usize, image: &Image) -> Option<i32> {
-    if position > image.width * image.height {
-        return None;
-    }
-
-    let mut pixel_index: usize = position;
-
-    while pixel_index < image.pixels.len() {
-        if image.pixels[pixel_index] != 2 {
-            // not transparent
-            return Some(image.pixels[pixel_index]);
-        }
-        pixel_index += image.width * image.height;
-    }
-
-    None
-}
-
-fn build_image(input_pixels: &Vec<i32>, width: usize, height: usize) -> Image {
-    Image {
-        pixels: input_pixels.clone(),
-        width,
-        height,
-        layers_count: input_pixels.len() / (width * height),
-    }
-}
-
-#[derive(Debug)]
-struct Image {
-    pixels: Vec<i32>,
-    layers_count: usize,
-    width: usize,
-    height: usize,
-}
-
-impl std::fmt::Display for Image {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut output = String::with_capacity(self.width * self.height + self.height);
-        // prints only first layer
-        for i in 0..(self.width * self.height) {
-            if i % (self.width) == 0 {
-                output.push('\n');
-            }
-            output.push_str(match self.pixels[i] {
-                0 => " ",
-                1 => "░",
-                _ => " ",
-            });
-        }
-        write!(f, "{}", output)
-    }
-}
@@ -1,6 +1,6 @@
+use aoc_2019::day09::get_instructions;
 use intcode::Vm;
 use std::collections::VecDeque;
-
 use std::io::{self, Error, Read, Write};
 
 fn main() -> Result<(), Error> {
@@ -22,11 +22,3 @@ fn main() -> Result<(), Error> {
 
     Ok(())
 }
-
-fn get_instructions(input: &mut String) -> Vec<i64> {
-    input.retain(|c| !c.is_whitespace());
-    input
-        .split(',')
-        .flat_map(|e| e.parse::<i64>())
-        .collect::<Vec<i64>>()
-}
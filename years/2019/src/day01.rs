@@ -0,0 +1,61 @@
+use aoc_utils::{Answer, Generator, Rng, Solution, SolutionError};
+
+pub fn compute_fuel_requirement(input_modules: &str, compute_fuel_for_fuel: bool) -> u32 {
+    input_modules
+        .lines()
+        .map(|m| compute_fuel_for_mass(m.parse::<u32>().unwrap(), compute_fuel_for_fuel))
+        .sum()
+}
+
+pub fn compute_fuel_for_mass(module: u32, compute_fuel_for_fuel: bool) -> u32 {
+    let mut fuel: u32 = 0;
+    let mut mass: u32 = module;
+    let fuel_for_mass = |weight: u32| -> u32 {
+        let fuel: i32 = (weight / 3) as i32 - 2;
+        if fuel <= 0 {
+            return 0;
+        }
+        fuel as u32
+    };
+
+    loop {
+        mass = fuel_for_mass(mass);
+        fuel += mass;
+
+        if !compute_fuel_for_fuel || mass == 0 {
+            break;
+        }
+    }
+
+    fuel
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(compute_fuel_requirement(input, false).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(compute_fuel_requirement(input, true).into())
+    }
+}
+
+impl Generator for Day01 {
+    const SIZE: &'static str = "modules";
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.range(50_000..=149_999)))
+            .collect()
+    }
+}
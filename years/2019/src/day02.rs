@@ -0,0 +1,56 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use intcode::Vm;
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+pub fn get_instructions(input: &mut String, noun: i64, verb: i64) -> Vec<i64> {
+    input.retain(|c| !c.is_whitespace());
+    let mut instructions: Vec<i64> = input
+        .split(',')
+        .flat_map(|e| e.parse::<i64>())
+        .collect::<Vec<i64>>();
+
+    instructions[1] = noun;
+    instructions[2] = verb;
+
+    instructions
+}
+
+pub const EXPECTED_OUTPUT: i64 = 19_690_720;
+
+/// Run the program with the given noun and verb, returning the value left at index 0
+pub fn run_program(input: &str, noun: i64, verb: i64) -> i64 {
+    let mut vm = Vm::new(
+        get_instructions(&mut input.to_string(), noun, verb),
+        VecDeque::new(),
+    );
+    vm.run(true);
+    vm.data()[0]
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(run_program(input, 12, 2).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+        for noun in 0..100 {
+            for verb in 0..100 {
+                if run_program(input, noun, verb) == EXPECTED_OUTPUT {
+                    return Ok(((100 * noun) + verb).into());
+                }
+            }
+        }
+        Err(format!("no noun and verb produce {}", EXPECTED_OUTPUT).into())
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_utils::{Answer, Direction4, Generator, Point2, Rng, Solution, SolutionError, YAxis};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+pub fn find_min_intersection_distance(
+    wire_1: &[Position],
+    wire_2: &[Position],
+) -> Option<i64> {
+    let mut min_distance: Option<i64> = None;
+    for p in get_intersections(wire_1, wire_2) {
+        let distance: i64 = p.manhattan(Point2::ORIGIN);
+        if min_distance.is_none() || min_distance.unwrap() > distance {
+            min_distance = Some(distance);
+        }
+    }
+    min_distance
+}
+
+pub fn find_min_steps_intersection(wire_1: &[Position], wire_2: &[Position]) -> Option<u32> {
+    let mut min_steps: Option<u32> = None;
+    for intersection in get_intersections(wire_1, wire_2) {
+        if let (Some(steps_w1), Some(steps_w2)) = (
+            steps_to_position(intersection, wire_1),
+            steps_to_position(intersection, wire_2),
+        ) {
+            let steps: u32 = steps_w1 + steps_w2;
+            if min_steps.is_none() || steps < min_steps.unwrap() {
+                min_steps = Some(steps)
+            }
+        }
+    }
+    min_steps
+}
+
+pub fn steps_to_position(position: Position, wire: &[Position]) -> Option<u32> {
+    for (steps, p) in (0_u32..).zip(wire.iter()) {
+        if position == *p {
+            return Some(steps);
+        }
+    }
+    None
+}
+
+pub fn get_intersections(wire_1: &[Position], wire_2: &[Position]) -> Vec<Position> {
+    let w1: HashSet<Position> = HashSet::from_iter(wire_1.iter().skip(1).cloned());
+    let w2: HashSet<Position> = HashSet::from_iter(wire_2.iter().skip(1).cloned());
+    w1.intersection(&w2).copied().collect::<Vec<Position>>()
+}
+
+pub fn compute_path(input: String) -> Vec<Position> {
+    let moves: Vec<Move> = input
+        .split(',')
+        .map(|e| get_move(e.to_string()))
+        .collect::<Vec<Move>>();
+
+    let mut positions: Vec<Position> = Vec::new();
+    positions.push(Point2::ORIGIN);
+    for m in moves {
+        let last_position: Position = *positions.last().unwrap();
+        for p in get_positions(m, last_position) {
+            positions.push(p);
+        }
+    }
+
+    positions
+}
+
+pub fn get_positions(m: Move, p: Position) -> Vec<Position> {
+    let mut positions: Vec<Position> = Vec::new();
+    let mut moves: i32 = 1;
+    let mut position: Position = p;
+    while moves <= m.distance {
+        position += m.direction.offset(YAxis::Up);
+        positions.push(position);
+        moves += 1;
+    }
+    positions
+}
+
+pub fn get_move(input: String) -> Move {
+    let mut chars = input.chars();
+    Move {
+        direction: Direction4::from_char(chars.next().unwrap()).unwrap(),
+        distance: chars.as_str().parse::<i32>().unwrap(),
+    }
+}
+
+/// Position of a wire, `y` growing upwards
+pub type Position = Point2;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Move {
+    direction: Direction4,
+    distance: i32,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 3;
+    /// Paths of both wires
+    type Input = (Vec<Position>, Vec<Position>);
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        let mut wires = input.lines();
+        let wire_1 = compute_path(wires.next().ok_or("missing first wire")?.to_string());
+        let wire_2 = compute_path(wires.next().ok_or("missing second wire")?.to_string());
+        Ok((wire_1, wire_2))
+    }
+
+    fn part1((wire_1, wire_2): &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(find_min_intersection_distance(wire_1, wire_2)
+            .ok_or("wires do not cross")?
+            .into())
+    }
+
+    fn part2((wire_1, wire_2): &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(find_min_steps_intersection(wire_1, wire_2)
+            .ok_or("wires do not cross")?
+            .into())
+    }
+}
+
+impl Generator for Day03 {
+    const SIZE: &'static str = "segments per wire";
+
+    /// Random walks, starting with segments crossing at (5, 0) so that the
+    /// wires always cross
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut wires = [
+            vec!["R10".to_string()],
+            vec!["U5".to_string(), "R5".to_string(), "D10".to_string()],
+        ];
+        for wire in wires.iter_mut() {
+            while wire.len() < size {
+                let direction = rng.choose(&['U', 'D', 'L', 'R']);
+                wire.push(format!("{}{}", direction, rng.range(1..=1000)));
+            }
+        }
+        wires.iter().map(|wire| wire.join(",") + "\n").collect()
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use std::collections::HashMap;
+
+pub fn find_matching_passwords(from: u32, to: u32, exactly_two_adjacent_digits: bool) -> Vec<u32> {
+    let mut passwords: Vec<u32> = Vec::new();
+    let mut current: u32 = from;
+    while current <= to {
+        let mut from_digits: Vec<u8> = to_digits(current);
+        let mut digit_to_set: Option<u8> = None;
+
+        // Initialize making digits from left to right increasing
+        for i in 0..from_digits.len() - 1 {
+            if from_digits[i] > from_digits[i + 1] && digit_to_set.is_none() {
+                digit_to_set = Some(from_digits[i]);
+            }
+            if let Some(digit) = digit_to_set {
+                from_digits[i + 1] = digit;
+            }
+        }
+        current = to_number(&from_digits);
+        if current > to {
+            break;
+        }
+
+        let mut has_two_adjacent_digits: bool = false;
+        let mut same = HashMap::<u8, usize>::new();
+        for i in 1..from_digits.len() {
+            if from_digits[i - 1] == from_digits[i] {
+                has_two_adjacent_digits = true;
+                *same.entry(from_digits[i - 1]).or_default() += 1;
+            }
+        }
+
+        if (!exactly_two_adjacent_digits && has_two_adjacent_digits)
+            || (exactly_two_adjacent_digits && same.iter().filter(|x| *x.1 == 1).count() > 0)
+        {
+            passwords.push(current);
+        }
+
+        current += 1;
+    }
+    passwords
+}
+
+pub fn to_number(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>()
+        .join("")
+        .parse::<u32>()
+        .unwrap()
+}
+
+pub fn to_digits(number: u32) -> Vec<u8> {
+    number
+        .to_string()
+        .chars()
+        .map(|e| e.to_string().parse::<u8>().unwrap())
+        .collect::<Vec<u8>>()
+}
+
+pub fn extract_bounds(input: String) -> Vec<u32> {
+    let input_split: Vec<&str> = input.split('-').collect::<Vec<&str>>();
+    let min: u32 = input_split[0].parse::<u32>().unwrap();
+    let max: u32 = input_split[1].parse::<u32>().unwrap();
+    vec![min, max]
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 4;
+    /// Lower and upper bounds of the passwords range
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(extract_bounds(
+            input.lines().next().ok_or("missing range")?.to_string(),
+        ))
+    }
+
+    fn part1(bounds: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(find_matching_passwords(bounds[0], bounds[1], false)
+            .len()
+            .into())
+    }
+
+    fn part2(bounds: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(find_matching_passwords(bounds[0], bounds[1], true)
+            .len()
+            .into())
+    }
+}
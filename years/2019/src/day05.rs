@@ -0,0 +1,42 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use intcode::Vm;
+use std::collections::VecDeque;
+
+pub fn get_instructions(input: &mut String) -> Vec<i64> {
+    input.retain(|c| !c.is_whitespace());
+    input
+        .split(',')
+        .flat_map(|e| e.parse::<i64>())
+        .collect::<Vec<i64>>()
+}
+
+/// Run the program with a single input, returning its last output
+pub fn run_diagnostic(instructions: &[i64], input: i64) -> Option<i64> {
+    let mut vm = Vm::new(instructions.to_vec(), VecDeque::from(vec![input]));
+    vm.run(false);
+    vm.outputs().last().copied()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 5;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_instructions(&mut input.to_string()))
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(run_diagnostic(instructions, 1)
+            .ok_or("program produced no output")?
+            .into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(run_diagnostic(instructions, 5)
+            .ok_or("program produced no output")?
+            .into())
+    }
+}
@@ -0,0 +1,107 @@
+use aoc_utils::search::bfs;
+use aoc_utils::{Answer, Solution, SolutionError};
+use std::collections::HashMap;
+
+/// Objects orbiting each object
+fn satellites<'a>(orbits: &HashMap<&'a str, &'a str>) -> HashMap<&'a str, Vec<&'a str>> {
+    let mut satellites: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (&orbit, &ancestor) in orbits {
+        satellites.entry(ancestor).or_default().push(orbit);
+    }
+    satellites
+}
+
+/// Sum of the depths of every object, from the objects orbiting nothing
+pub fn get_direct_indirect_orbits(orbits: HashMap<&str, &str>) -> usize {
+    let satellites = satellites(&orbits);
+    let roots = satellites
+        .keys()
+        .copied()
+        .filter(|object| !orbits.contains_key(object));
+    bfs(
+        roots,
+        |object| satellites.get(object).into_iter().flatten().copied(),
+        |_| false,
+    )
+    .distances()
+    .map(|(_, depth)| depth)
+    .sum()
+}
+
+/// Transfers between the objects orbited by `point_1` and `point_2`
+pub fn get_min_transferts(
+    orbits: HashMap<&str, &str>,
+    point_1: &str,
+    point_2: &str,
+) -> Option<usize> {
+    let satellites = satellites(&orbits);
+    let (&from, &to) = (orbits.get(point_1)?, orbits.get(point_2)?);
+    bfs(
+        [from],
+        |object| {
+            let ancestor = orbits.get(object).copied();
+            let satellites = satellites.get(object).into_iter().flatten().copied();
+            ancestor.into_iter().chain(satellites)
+        },
+        |&object| object == to,
+    )
+    .goal_distance()
+}
+
+pub fn build_orbits(input: &str) -> HashMap<&str, &str> {
+    let mut orbits: HashMap<&str, &str> = HashMap::new();
+    for l in input.lines() {
+        let s = l.split(')').collect::<Vec<&str>>();
+        let (ancestor, orbit) = (s[0], s[1]);
+        orbits.entry(orbit).or_insert(ancestor);
+    }
+    orbits
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 6;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_direct_indirect_orbits(build_orbits(input)).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_min_transferts(build_orbits(input), "YOU", "SAN")
+            .ok_or("no transfer path between YOU and SAN")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\n";
+
+    #[test]
+    fn test_orbits() {
+        assert_eq!(42, get_direct_indirect_orbits(build_orbits(EXAMPLE)));
+    }
+
+    #[test]
+    fn test_transfers() {
+        let input = format!("{}K)YOU\nI)SAN\n", EXAMPLE);
+
+        assert_eq!(
+            Some(4),
+            get_min_transferts(build_orbits(&input), "YOU", "SAN")
+        );
+        assert_eq!(
+            None,
+            get_min_transferts(build_orbits(&input), "YOU", "NOBODY")
+        );
+    }
+}
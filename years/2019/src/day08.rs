@@ -0,0 +1,101 @@
+use aoc_utils::{Answer, Grid, Point2, Solution, SolutionError};
+
+pub fn count_in_layer(input: i32, layer: usize, image: &Image) -> i32 {
+    image.layers[layer]
+        .values()
+        .filter(|&&pixel| pixel == input)
+        .count() as i32
+}
+
+pub fn find_layer_having_fewest(input: i32, image: &Image) -> Option<(usize, i32)> {
+    (0..image.layers.len())
+        .map(|layer| (layer, count_in_layer(input, layer, image)))
+        .min_by_key(|&(_, count)| count)
+}
+
+pub fn merge_layers(image: &Image) -> Image {
+    Image {
+        layers: vec![Grid::from_fn(image.width, image.height, |p| {
+            // transparent when every layer is
+            get_pixel_value(p, image).unwrap_or(2)
+        })],
+        width: image.width,
+        height: image.height,
+    }
+}
+
+/// Value of the first layer not transparent at `position`
+pub fn get_pixel_value(position: Point2, image: &Image) -> Option<i32> {
+    image
+        .layers
+        .iter()
+        .filter_map(|layer| layer.get(position))
+        .find(|&&pixel| pixel != 2)
+        .copied()
+}
+
+pub fn build_image(input_pixels: &[i32], width: usize, height: usize) -> Image {
+    Image {
+        layers: input_pixels
+            .chunks_exact(width * height)
+            .map(|layer| {
+                Grid::new(width, height, layer.to_vec()).expect("layers hold width * height pixels")
+            })
+            .collect(),
+        width,
+        height,
+    }
+}
+
+#[derive(Debug)]
+pub struct Image {
+    layers: Vec<Grid<i32>>,
+    width: usize,
+    height: usize,
+}
+
+impl std::fmt::Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // prints only first layer, each row after a newline
+        match self.layers.first() {
+            Some(layer) => write!(
+                f,
+                "\n{}",
+                layer.display(|&pixel| if pixel == 1 { '░' } else { ' ' })
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+pub const IMAGE_WIDTH: usize = 25;
+pub const IMAGE_HEIGHT: usize = 6;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 8;
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        let input_pixels: Vec<i32> = input
+            .trim()
+            .chars()
+            .map(|c| c.to_string().parse::<i32>())
+            .collect::<Result<Vec<i32>, _>>()?;
+        Ok(build_image(&input_pixels, IMAGE_WIDTH, IMAGE_HEIGHT))
+    }
+
+    fn part1(image: &Self::Input) -> Result<Answer, SolutionError> {
+        let (layer_having_fewest_0, _count_0) =
+            find_layer_having_fewest(0, image).ok_or("image has no layer")?;
+        Ok((count_in_layer(1, layer_having_fewest_0, image)
+            * count_in_layer(2, layer_having_fewest_0, image))
+        .into())
+    }
+
+    fn part2(image: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(merge_layers(image).to_string().into())
+    }
+}
@@ -0,0 +1,238 @@
+use aoc_utils::math::gcd;
+use aoc_utils::{Answer, Solution, SolutionError};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+/// Direction `(dx, dy)` reduced by its gcd, `y` growing downwards
+pub type Direction = (i64, i64);
+
+/// Order of directions swept clockwise by the laser, starting upwards
+pub fn clockwise(a: Direction, b: Direction) -> Ordering {
+    // right half, from up included to down excluded, comes first
+    let half = |(dx, dy): Direction| !(dx > 0 || (dx == 0 && dy < 0));
+    half(a)
+        .cmp(&half(b))
+        .then_with(|| (b.0 * a.1).cmp(&(a.0 * b.1)))
+}
+
+/// Asteroids in the order they are vaporized, each turn of the laser hitting the nearest
+/// asteroid of every direction
+pub fn vaporize(
+    asteroid_position: &Position,
+    asteroids_positions: &[Position],
+) -> Vec<Position> {
+    let mut lines: Vec<(Direction, Vec<ExploredAsteroid>)> =
+        get_detected_asteroids(asteroid_position, asteroids_positions)
+            .into_iter()
+            .collect();
+    lines.sort_by(|(a, _), (b, _)| clockwise(*a, *b));
+    for (_, asteroids) in lines.iter_mut() {
+        // nearest last, to be popped first
+        asteroids.sort_by_key(|asteroid| Reverse(asteroid.distance_from_source));
+    }
+
+    let mut vaporized: Vec<Position> = Vec::new();
+    while lines.iter().any(|(_, asteroids)| !asteroids.is_empty()) {
+        for (_, asteroids) in lines.iter_mut() {
+            if let Some(asteroid) = asteroids.pop() {
+                vaporized.push(asteroid.position);
+            }
+        }
+    }
+
+    vaporized
+}
+
+/// Other asteroids, grouped by their exact direction from `asteroid_position`
+pub fn get_detected_asteroids(
+    asteroid_position: &Position,
+    asteroids_positions: &[Position],
+) -> HashMap<Direction, Vec<ExploredAsteroid>> {
+    let mut detected_asteroids: HashMap<Direction, Vec<ExploredAsteroid>> = HashMap::new();
+
+    for other_asteroid_position in asteroids_positions.iter() {
+        if other_asteroid_position.asteroid.is_some()
+            && other_asteroid_position != asteroid_position
+        {
+            let dx = other_asteroid_position.x as i64 - asteroid_position.x as i64;
+            let dy = other_asteroid_position.y as i64 - asteroid_position.y as i64;
+            let steps = gcd(dx, dy);
+
+            detected_asteroids
+                .entry((dx / steps, dy / steps))
+                .or_default()
+                .push(ExploredAsteroid {
+                    position: *other_asteroid_position,
+                    distance_from_source: steps,
+                });
+        }
+    }
+
+    detected_asteroids
+}
+
+pub fn find_asteroid_detecting_most_asteroids(map: &Map) -> Option<PositionDetected> {
+    let mut result: Option<PositionDetected> = None;
+
+    for position in map.positions.iter() {
+        if position.asteroid.is_none() {
+            continue;
+        }
+
+        let detected_asteroids: usize = get_detected_asteroids(position, &map.positions).len();
+        if result.is_none() || detected_asteroids > result.as_ref().unwrap().detected {
+            result = Some(PositionDetected {
+                position: *position,
+                detected: detected_asteroids,
+            });
+        }
+    }
+
+    result
+}
+
+pub fn build_map(input: String) -> Result<Map, Error> {
+    let mut positions: Vec<Position> = Vec::new();
+    let mut width: usize = 0;
+    let mut height: usize = 0;
+
+    for row in input.lines() {
+        height = 0;
+        for cell in row.chars() {
+            positions.push(Position {
+                x: height,
+                y: width,
+                asteroid: match cell {
+                    '#' => Some(Asteroid {}),
+                    '.' => None,
+                    _ => return Err(Error::new(ErrorKind::InvalidInput, "unknown char found!")),
+                },
+            });
+            height += 1;
+        }
+        width += 1;
+    }
+
+    Ok(Map {
+        width,
+        height,
+        positions,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Asteroid {}
+
+#[derive(Debug, Clone)]
+pub struct ExploredAsteroid {
+    position: Position,
+    /// Asteroid-free steps in its direction, the nearest being at 1
+    distance_from_source: i64,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+    pub asteroid: Option<Asteroid>,
+}
+
+#[derive(Debug)]
+pub struct PositionDetected {
+    pub position: Position,
+    pub detected: usize,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    pub positions: Vec<Position>,
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut output = String::with_capacity(self.width * self.height + self.height);
+        for i in 0..self.positions.len() {
+            if i % (self.width) == 0 {
+                output.push('\n');
+            }
+            output.push_str(match &self.positions[i].asteroid {
+                Some(_) => "#",
+                None => ".",
+            });
+        }
+
+        write!(f, "{}", output)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 10;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(build_map(input.to_string())?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolutionError> {
+        let best_position =
+            find_asteroid_detecting_most_asteroids(map).ok_or("map has no asteroid")?;
+        Ok(best_position.detected.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, SolutionError> {
+        let best_position =
+            find_asteroid_detecting_most_asteroids(map).ok_or("map has no asteroid")?;
+        let vaporized = vaporize(&best_position.position, &map.positions);
+        let vaporized_200th = vaporized
+            .get(199)
+            .ok_or("less than 200 asteroids vaporized")?;
+        Ok((vaporized_200th.x * 100 + vaporized_200th.y).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+        .#..##.###...#######\n##.############..##.\n.#.######.########.#\n\
+        .###.#######.####.#.\n#####.##.#.##.###.##\n..#####..#.#########\n\
+        ####################\n#.####....###.#.#.##\n##.#################\n\
+        #####.##.###..####..\n..######..##.#######\n####.##.####...##..#\n\
+        .#####..#.######.###\n##...#.##########...\n#.##########.#######\n\
+        .####.#.###.###.#.##\n....##.##.###..#####\n.#.#.###########.###\n\
+        #.#.#.#####.####.###\n###.##.####.##.#..##\n";
+
+    #[test]
+    fn test_clockwise() {
+        let mut directions = vec![(-1, -1), (0, 1), (1, 0), (-1, 0), (1, -2), (0, -1), (1, 1)];
+        directions.sort_by(|&a, &b| clockwise(a, b));
+
+        assert_eq!(
+            vec![(0, -1), (1, -2), (1, 0), (1, 1), (0, 1), (-1, 0), (-1, -1)],
+            directions
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let map = build_map(EXAMPLE.to_string()).unwrap();
+        let best_position = find_asteroid_detecting_most_asteroids(&map).unwrap();
+        let Position { x, y, .. } = best_position.position;
+        assert_eq!((11, 13, 210), (x, y, best_position.detected));
+
+        let vaporized = vaporize(&best_position.position, &map.positions);
+        let coordinates = |n: usize| (vaporized[n - 1].x, vaporized[n - 1].y);
+        assert_eq!((11, 12), coordinates(1));
+        assert_eq!((12, 1), coordinates(2));
+        assert_eq!((8, 2), coordinates(200));
+        assert_eq!((11, 1), coordinates(299));
+        assert_eq!(299, vaporized.len());
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_utils::{
+    Answer, Bounds, Direction4, Point2, Solution, SolutionError, SparseGrid, Turn, YAxis,
+};
+use intcode::{State, Vm};
+use std::collections::VecDeque;
+
+pub fn get_instructions(input: &mut String) -> Vec<i64> {
+    input.retain(|c| !c.is_whitespace());
+    input
+        .split(',')
+        .flat_map(|e| e.parse::<i64>())
+        .collect::<Vec<i64>>()
+}
+
+/// Turn asked by the robot
+pub fn get_turn(turn_raw: i64) -> Option<Turn> {
+    match turn_raw {
+        0 => Some(Turn::Left),
+        1 => Some(Turn::Right),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Unknown = -1,
+    Black = 0,
+    White = 1,
+}
+
+/// Hull and painting robot, `y` growing downwards
+pub struct Map {
+    current_position: Point2,
+    current_direction: Direction4,
+    /// Painted panels, every other one being black
+    panels: SparseGrid<Color>,
+}
+
+impl Map {
+    pub fn new(current_position: Point2, current_direction: Direction4) -> Map {
+        Map {
+            current_position,
+            current_direction,
+            panels: SparseGrid::new(Color::Black),
+        }
+    }
+
+    pub fn get_current_position_color(&self) -> Color {
+        *self.panels.get(self.current_position)
+    }
+
+    pub fn paint_current_position(&mut self, color: Color) {
+        self.panels.set(self.current_position, color);
+    }
+
+    /// Number of panels painted at least once
+    pub fn painted_count(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Painted area, white panels drawn as `#`
+    pub fn render_panels(&self) -> String {
+        self.panels.render(|color| match color {
+            Color::White => '#',
+            _ => '.',
+        })
+    }
+
+    pub fn turn_and_move(&mut self, turn: Turn, distance: i64) {
+        self.current_direction = self.current_direction.turn(turn);
+        self.current_position += self.current_direction.offset(YAxis::Down) * distance;
+    }
+
+    /// Run the painting robot until it halts
+    pub fn paint(&mut self, instructions: Vec<i64>) {
+        let vm = &mut Vm::new(instructions, VecDeque::new());
+
+        loop {
+            vm.add_input(match self.get_current_position_color() {
+                Color::Black => 0,
+                Color::White => 1,
+                Color::Unknown => -1,
+            });
+
+            // each step outputs a color and a turn, unless the robot halts
+            let outputs_count = vm.outputs().len();
+            for _ in 0..2 {
+                if vm.state() != &State::Stopped {
+                    vm.run(true);
+                }
+            }
+            let [color_raw, turn_raw] = match vm.outputs()[outputs_count..] {
+                [color_raw, turn_raw] => [color_raw, turn_raw],
+                _ => break,
+            };
+
+            self.paint_current_position(match color_raw {
+                0 => Color::Black,
+                1 => Color::White,
+                _ => Color::Unknown,
+            });
+            if let Some(turn) = get_turn(turn_raw) {
+                self.turn_and_move(turn, 1);
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let robot = self.current_position;
+        let mut bounds = self.panels.bounds().unwrap_or(Bounds::of(robot));
+        bounds.include(robot);
+
+        let mut output = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for y in bounds.min.y..=bounds.max.y {
+            output.push('\n');
+            for x in bounds.min.x..=bounds.max.x {
+                let p = Point2::new(x, y);
+                if p == robot {
+                    output.push(self.current_direction.arrow());
+                } else {
+                    output.push(match self.panels.get(p) {
+                        Color::Black => '.',
+                        Color::White => '#',
+                        Color::Unknown => ' ',
+                    });
+                }
+            }
+        }
+
+        write!(f, "{}", output)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 11;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_instructions(&mut input.to_string()))
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer, SolutionError> {
+        let mut map = Map::new(Point2::ORIGIN, Direction4::North);
+        map.paint(instructions.clone());
+        Ok(map.painted_count().into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer, SolutionError> {
+        let mut map = Map::new(Point2::ORIGIN, Direction4::North);
+        map.paint_current_position(Color::White);
+        map.paint(instructions.clone());
+        Ok(format!("\n{}", map.render_panels()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Program ignoring its inputs and outputting the steps of the puzzle example
+    fn example_robot() -> Vec<i64> {
+        let outputs = [1, 0, 0, 0, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0];
+        let mut program: Vec<i64> = outputs.iter().flat_map(|&o| [104, o]).collect();
+        program.push(99);
+        program
+    }
+
+    #[test]
+    fn test_paint() {
+        let mut map = Map::new(Point2::ORIGIN, Direction4::North);
+        map.paint(example_robot());
+
+        assert_eq!(6, map.painted_count());
+        assert_eq!("..#\n..#\n##.", map.render_panels());
+        assert_eq!("\n.<#\n..#\n##.", map.to_string());
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_utils::{Answer, Grid, GridError, Point2, Solution, SolutionError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Geology {
+    Tree,
+    Free,
+}
+
+impl Geology {
+    pub fn from_char(c: char) -> Option<Geology> {
+        match c {
+            '#' => Some(Geology::Tree),
+            '.' => Some(Geology::Free),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_map(input: &str) -> Result<Grid<Geology>, GridError> {
+    Grid::parse(input, Geology::from_char)
+}
+
+#[derive(Clone, Copy)]
+pub struct Move {
+    right: usize,
+    bottom: usize,
+}
+
+impl Move {
+    pub fn new(right: usize, bottom: usize) -> Move {
+        Move { right, bottom }
+    }
+}
+
+/// Count trees met while sliding down the map with the given move
+///
+/// The map repeats rightwards, but not downwards.
+pub fn count_trees(map: &Grid<Geology>, mov: Move) -> usize {
+    if map.width() == 0 {
+        return 0;
+    }
+
+    (1..)
+        .map(|step| {
+            Point2::new(
+                ((step * mov.right) % map.width()) as i64,
+                (step * mov.bottom) as i64,
+            )
+        })
+        .take_while(|&p| map.contains(p))
+        .filter(|&p| map[p] == Geology::Tree)
+        .count()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+    type Input = Grid<Geology>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(count_trees(map, Move::new(3, 1)).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, SolutionError> {
+        let moves: Vec<Move> = vec![
+            Move::new(1, 1),
+            Move::new(3, 1),
+            Move::new(5, 1),
+            Move::new(7, 1),
+            Move::new(1, 2),
+        ];
+
+        Ok(moves
+            .into_iter()
+            .map(|m| count_trees(map, m))
+            .product::<usize>()
+            .into())
+    }
+}
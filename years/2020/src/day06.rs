@@ -0,0 +1,86 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+
+pub fn get_groups_anyone_yes_answers(input: &str) -> Vec<u32> {
+    let mut answers: Vec<u32> = Vec::new();
+    let a_index: u32 = 'a' as u32;
+    let z_index: u32 = 'z' as u32;
+
+    let mut group_answers: u32 = 0;
+
+    for line in input.lines() {
+        for c in line.chars() {
+            let c_index: u32 = c as u32;
+            if c_index >= a_index && c_index <= z_index {
+                group_answers |= 1 << (c_index % (z_index - a_index + 1));
+            }
+        }
+
+        if line.is_empty() {
+            answers.push(group_answers);
+            group_answers = 0;
+        }
+    }
+    answers.push(group_answers);
+
+    answers
+}
+
+// TODO: To be refactored using the part 1 method
+pub fn get_groups_everyone_yes_answers(input: &str) -> Vec<u32> {
+    let mut answers: Vec<u32> = Vec::new();
+    let a_index: u32 = 'a' as u32;
+    let z_index: u32 = 'z' as u32;
+
+    let mut group_answers: u32 = u32::MAX;
+
+    for line in input.lines() {
+        if !line.is_empty() {
+            let mut individual_answer: u32 = 0;
+            for c in line.chars() {
+                let c_index: u32 = c as u32;
+                if c_index >= a_index && c_index <= z_index {
+                    individual_answer |= 1 << (c_index % (z_index - a_index + 1));
+                }
+            }
+
+            group_answers &= individual_answer;
+        } else {
+            answers.push(group_answers);
+            group_answers = u32::MAX;
+        }
+    }
+    answers.push(group_answers);
+
+    answers
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_groups_anyone_yes_answers(input)
+            .iter()
+            .map(|g| g.count_ones())
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_groups_everyone_yes_answers(input)
+            .iter()
+            .map(|g| g.count_ones())
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,274 @@
+use aoc_utils::search::bfs;
+use aoc_utils::{Answer, Solution, SolutionError};
+use std::collections::HashMap;
+use std::io::Error;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Color {
+    v: String,
+}
+
+impl Color {
+    pub fn new(v: String) -> Color {
+        Color { v }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FinishType {
+    v: String,
+}
+
+impl FinishType {
+    pub fn new(v: String) -> FinishType {
+        FinishType { v }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BagSlot {
+    b: Bag,
+    count: u32,
+}
+
+impl BagSlot {
+    pub fn new(b: Bag, count: u32) -> BagSlot {
+        BagSlot { b, count }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bag {
+    c: Color,
+    t: FinishType,
+}
+
+impl Bag {
+    pub fn new(c: Color, t: FinishType) -> Bag {
+        Bag { c, t }
+    }
+
+    pub fn name(&self) -> String {
+        [self.t.v.as_str(), self.c.v.as_str()].join(" ")
+    }
+}
+
+impl FromStr for Bag {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Bag, Error> {
+        if let Some((finish_type, color)) = input.split_once(" ") {
+            return Ok(Bag::new(
+                Color::new(color.trim().to_string()),
+                FinishType::new(finish_type.trim().to_string()),
+            ));
+        }
+
+        Err(Error::other("cannot parse"))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Rules {
+    r: HashMap<String, Vec<BagSlot>>,
+}
+
+impl Rules {
+    pub fn new() -> Rules {
+        Rules { r: HashMap::new() }
+    }
+
+    pub fn add(&mut self, b: &Bag, s: Option<&BagSlot>) {
+        if let Some(v) = self.r.get_mut(b.name().as_str()) {
+            if let Some(s) = s {
+                (*v).push(s.clone());
+            }
+            return;
+        }
+
+        let slot_to_add = match s {
+            Some(s) => vec![s.clone()],
+            None => Vec::new(),
+        };
+        self.r.insert(b.name(), slot_to_add);
+    }
+
+    /// Names of the bags directly inside the bag named `name`
+    fn contents(&self, name: &str) -> Vec<String> {
+        self.r
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|slot| slot.b.name())
+            .collect()
+    }
+
+    pub fn bag_can_contain(&self, from: &Bag, to: &Bag) -> bool {
+        let to = to.name();
+        bfs(
+            self.contents(&from.name()),
+            |name| self.contents(name),
+            |name| *name == to,
+        )
+        .goal()
+        .is_some()
+    }
+
+    /// Number of bags eventually containing `to`
+    pub fn bag_can_contain_count(&self, to: &Bag) -> usize {
+        let mut containers: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, subs) in &self.r {
+            for sub in subs {
+                containers
+                    .entry(sub.b.name())
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+        let search = bfs(
+            [to.name()],
+            |name| containers.get(name).into_iter().flatten().cloned(),
+            |_| false,
+        );
+        // the bag itself is reached first
+        search.len() - 1
+    }
+
+    /// Number of bags, `b` included, the count of each bag type computed once
+    pub fn bag_count(&self, b: &Bag) -> usize {
+        self.count_bags(&b.name(), &mut HashMap::new())
+    }
+
+    fn count_bags(&self, name: &str, counts: &mut HashMap<String, usize>) -> usize {
+        if let Some(&count) = counts.get(name) {
+            return count;
+        }
+        let count = match self.r.get(name) {
+            Some(subs) => {
+                1 + subs
+                    .iter()
+                    .map(|s| self.count_bags(&s.b.name(), counts) * s.count as usize)
+                    .sum::<usize>()
+            }
+            None => 0,
+        };
+        counts.insert(name.to_string(), count);
+        count
+    }
+}
+
+impl FromStr for Rules {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Rules, Error> {
+        let mut rules = Rules::new();
+
+        for line in input.lines() {
+            if let Some((bag_raw_str, contained_bags_raw_str)) = line.split_once("contain") {
+                let bag: Bag = Bag::from_str(
+                    bag_raw_str
+                        .replace(".", "")
+                        .replace(",", "")
+                        .replace("bags", "")
+                        .replace("bag", "")
+                        .trim(),
+                )?;
+                rules.add(&bag, None);
+
+                for contained_bag_raw_str in contained_bags_raw_str.split(',') {
+                    if contained_bag_raw_str.trim() == "no other bags" {
+                        break;
+                    }
+                    if let Some((count, sub_bag)) = contained_bag_raw_str.trim().split_once(" ") {
+                        if sub_bag.starts_with("other bags.") {
+                            break;
+                        }
+                        rules.add(
+                            &bag,
+                            Some(&BagSlot::new(
+                                Bag::from_str(
+                                    sub_bag
+                                        .replace(".", "")
+                                        .replace(",", "")
+                                        .replace("bags", "")
+                                        .replace("bag", "")
+                                        .trim(),
+                                )?,
+                                count.parse::<u32>().unwrap(),
+                            )),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(rules)
+    }
+}
+
+pub fn shiny_gold_bag() -> Bag {
+    Bag::new(
+        Color::new("gold".to_string()),
+        FinishType::new("shiny".to_string()),
+    )
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(Rules::from_str(input)?)
+    }
+
+    fn part1(rules: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(rules.bag_can_contain_count(&shiny_gold_bag()).into())
+    }
+
+    fn part2(rules: &Self::Input) -> Result<Answer, SolutionError> {
+        // the shiny gold bag itself is not counted
+        Ok((rules.bag_count(&shiny_gold_bag()) - 1).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bag_can_contain() {
+        let rules = Rules::from_str(
+            "light red bags contain 1 bright white bag.\n\
+             bright white bags contain 1 shiny gold bag.\n\
+             shiny gold bags contain no other bags.\n",
+        )
+        .unwrap();
+        let bag = |name: &str| Bag::from_str(name).unwrap();
+
+        assert!(rules.bag_can_contain(&bag("light red"), &bag("shiny gold")));
+        assert!(!rules.bag_can_contain(&bag("shiny gold"), &bag("light red")));
+        assert!(!rules.bag_can_contain(&bag("shiny gold"), &bag("shiny gold")));
+        assert_eq!(2, rules.bag_can_contain_count(&shiny_gold_bag()));
+    }
+
+    #[test]
+    fn test_bag_count() {
+        let rules = Rules::from_str(
+            "shiny gold bags contain 2 dark red bags.\n\
+             dark red bags contain 2 dark orange bags.\n\
+             dark orange bags contain 2 dark yellow bags.\n\
+             dark yellow bags contain 2 dark green bags.\n\
+             dark green bags contain 2 dark blue bags.\n\
+             dark blue bags contain 2 dark violet bags.\n\
+             dark violet bags contain no other bags.\n",
+        )
+        .unwrap();
+
+        assert_eq!(127, rules.bag_count(&shiny_gold_bag()));
+        assert_eq!(1, rules.bag_count(&Bag::from_str("dark violet").unwrap()));
+    }
+}
@@ -0,0 +1,150 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+use parse_display::{Display, FromStr};
+use std::collections::HashSet;
+use std::io::Error;
+
+#[derive(Display, FromStr, PartialEq, Debug, Copy, Clone)]
+#[allow(clippy::enum_variant_names)]
+pub enum Operation {
+    #[display("acc {0}")]
+    Accumulate(i32),
+
+    #[display("jmp {0}")]
+    Jump(i32),
+
+    #[display("nop {0}")]
+    NoOperation(i32),
+}
+
+pub struct Executor<'a> {
+    pub accumulator: i32,
+    code_pointer: usize,
+    operations: &'a Vec<Operation>,
+    executed_operations: HashSet<usize>,
+    instruction_to_be_changed_pointer: Option<usize>,
+}
+
+impl<'a> Executor<'a> {
+    const EMTPY_OPERATIONS: &'a Vec<Operation> = &Vec::new();
+
+    pub fn new() -> Executor<'a> {
+        Executor {
+            accumulator: 0,
+            code_pointer: 0,
+            operations: Executor::EMTPY_OPERATIONS,
+            executed_operations: HashSet::new(),
+            instruction_to_be_changed_pointer: None,
+        }
+    }
+
+    pub fn set_operations(&mut self, operations: &'a Vec<Operation>) {
+        self.operations = operations;
+    }
+
+    pub fn reset(&mut self) {
+        self.code_pointer = 0;
+        self.accumulator = 0;
+        self.executed_operations = HashSet::new();
+    }
+
+    pub fn execute(&mut self) -> Result<(), Error> {
+        if self.instruction_to_be_changed_pointer.is_none() {
+            self.instruction_to_be_changed_pointer = Some(0)
+        } else {
+            self.instruction_to_be_changed_pointer = Some(
+                (self.instruction_to_be_changed_pointer.unwrap() + 1)
+                    .rem_euclid(self.operations.len()),
+            );
+        }
+
+        let mut operation_changed = false;
+
+        while self.code_pointer < self.operations.len() {
+            let mut operation: Operation = self.operations[self.code_pointer];
+
+            if !operation_changed
+                && self.instruction_to_be_changed_pointer.is_some()
+                && self.instruction_to_be_changed_pointer.unwrap() == self.code_pointer
+            {
+                operation = match operation {
+                    Operation::Jump(value) => Operation::NoOperation(value),
+                    Operation::NoOperation(value) => Operation::Jump(value),
+                    _ => operation,
+                };
+
+                operation_changed = true;
+            }
+
+            match operation {
+                Operation::Accumulate(value) => {
+                    self.accumulator += value;
+                    self.code_pointer += 1;
+                }
+                Operation::Jump(value) => {
+                    self.code_pointer = (self.code_pointer as i32 + value) as usize;
+                }
+                Operation::NoOperation(_) => {
+                    self.code_pointer += 1;
+                }
+            }
+
+            if self.executed_operations.contains(&self.code_pointer) {
+                return Err(Error::other("LOOP!"));
+            } else {
+                self.executed_operations.insert(self.code_pointer);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Executor<'_> {
+    fn default() -> Self {
+        Executor::new()
+    }
+}
+
+pub fn get_operations_from_input(input: &str) -> Result<Vec<Operation>, Error> {
+    Ok(input
+        .lines()
+        .map(|l| l.parse().unwrap())
+        .collect::<Vec<Operation>>())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_operations_from_input(input)?)
+    }
+
+    fn part1(operations: &Self::Input) -> Result<Answer, SolutionError> {
+        let mut executor = Executor::new();
+        executor.set_operations(operations);
+
+        match executor.execute() {
+            Ok(_) => Err("program terminated, it should have looped".into()),
+            Err(_) => Ok(executor.accumulator.into()),
+        }
+    }
+
+    fn part2(operations: &Self::Input) -> Result<Answer, SolutionError> {
+        let mut executor = Executor::new();
+        executor.set_operations(operations);
+
+        // each execution changes the next instruction, give up once all have been tried
+        for _ in 0..operations.len() {
+            executor.reset();
+            if executor.execute().is_ok() {
+                return Ok(executor.accumulator.into());
+            }
+        }
+
+        Err("no single instruction change makes the program terminate".into())
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+
+pub fn get_numbers_list_from_str(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .flat_map(|e| e.trim().parse::<i64>())
+        .collect::<Vec<i64>>()
+}
+
+pub fn get_first_invalid_entry(data: &[i64], preambule_length: usize) -> Option<i64> {
+    if preambule_length >= data.len() {
+        return None;
+    }
+
+    let mut index: usize = preambule_length;
+
+    while index < data.len() {
+        if find_two_numbers_summing_to(&data[index - preambule_length..index], data[index])
+            .is_none()
+        {
+            return Some(data[index]);
+        }
+        index += 1;
+    }
+
+    None
+}
+
+pub fn find_two_numbers_summing_to(numbers: &[i64], target_sum: i64) -> Option<(i64, i64)> {
+    if !numbers.is_empty() {
+        // Sort vec
+        let mut sorted_numbers = numbers.to_vec();
+        sorted_numbers.sort_unstable();
+
+        let mut lower_bound_index: usize = 0;
+        let mut higher_bound_index: usize = sorted_numbers.len() - 1;
+
+        // Finding numbers suming to sum
+        while lower_bound_index < higher_bound_index {
+            let computed_sum: i64 =
+                sorted_numbers[lower_bound_index] + sorted_numbers[higher_bound_index];
+
+            if computed_sum == target_sum {
+                return Some((
+                    sorted_numbers[lower_bound_index],
+                    sorted_numbers[higher_bound_index],
+                ));
+            }
+            if computed_sum < target_sum {
+                lower_bound_index += 1;
+            }
+            if computed_sum > target_sum {
+                higher_bound_index -= 1;
+            }
+        }
+    }
+
+    None
+}
+
+pub fn get_subset_summing_to(numbers: Vec<i64>, target_sum: i64) -> Option<Vec<i64>> {
+    let mut from_index: usize = 0;
+    let mut to_index: usize = 1;
+
+    while from_index < numbers.len() {
+        let current_sum: i64 = numbers[from_index..to_index + 1].iter().sum();
+
+        if current_sum == target_sum {
+            return Some(numbers[from_index..to_index + 1].to_vec());
+        } else if current_sum < target_sum {
+            to_index += 1;
+        } else {
+            from_index += 1;
+            to_index = from_index + 1;
+        }
+    }
+
+    None
+}
+
+pub const PREAMBULE_SIZE: usize = 25;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_numbers_list_from_str(input))
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_first_invalid_entry(numbers, PREAMBULE_SIZE)
+            .ok_or("no invalid entry")?
+            .into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer, SolutionError> {
+        let first_invalid_entry =
+            get_first_invalid_entry(numbers, PREAMBULE_SIZE).ok_or("no invalid entry")?;
+        let subset = get_subset_summing_to(numbers.clone(), first_invalid_entry)
+            .ok_or("no subset summing to the first invalid entry")?;
+        let min_value = subset.iter().min().ok_or("empty subset")?;
+        let max_value = subset.iter().max().ok_or("empty subset")?;
+        Ok((min_value + max_value).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_first_invalid_entry() {
+        // Setup:
+        const PREAMBULE_LENGTH: usize = 5;
+        let input = vec![
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+
+        // Execute:
+        let result = get_first_invalid_entry(&input, PREAMBULE_LENGTH);
+
+        // Verify:
+        assert_eq!(Some(127), result);
+    }
+
+    #[test]
+    fn test_get_subset_summing_to() {
+        // Setup:
+        const NUMBER_TARGET: i64 = 127;
+        let input = vec![
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+
+        // Execute:
+        let result = get_subset_summing_to(input, NUMBER_TARGET);
+
+        // Verify:
+        assert_eq!(Some(vec![15, 25, 47, 40]), result);
+    }
+}
@@ -4,3 +4,38 @@
 pub use aoc_utils::*;
 
 // Day modules
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+/// Add every 2020 solution to the registry
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day01::Day01>()
+        .register::<day02::Day02>()
+        .register::<day03::Day03>()
+        .register::<day04::Day04>()
+        .register::<day05::Day05>()
+        .register::<day06::Day06>()
+        .register::<day07::Day07>()
+        .register::<day08::Day08>()
+        .register::<day09::Day09>()
+        .register::<day10::Day10>();
+}
+
+#[cfg(test)]
+mod example_tests {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod input_tests {
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+}
@@ -0,0 +1,143 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+
+#[derive(Debug, PartialEq)]
+pub enum DepthVariation {
+    NA,
+    Increase,
+    Decrease,
+    Stable,
+}
+
+pub fn get_numbers_list_from_str(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .flat_map(|e| e.trim().parse::<i32>())
+        .collect::<Vec<i32>>()
+}
+
+pub fn get_variations(numbers: Vec<i32>) -> Vec<DepthVariation> {
+    let mut previous_depth: Option<i32> = None;
+    let mut results: Vec<DepthVariation> = Vec::with_capacity(numbers.len());
+
+    for &depth in numbers.iter() {
+        results.push(match previous_depth {
+            None => DepthVariation::NA,
+            Some(previous_depth) => if depth - previous_depth < 0 {
+                DepthVariation::Decrease
+            } else if depth - previous_depth > 0 {
+                DepthVariation::Increase
+            } else {
+                DepthVariation::Stable
+            },
+        });
+        
+        previous_depth = Some(depth);
+    }
+
+    results
+}
+
+pub fn get_variations_windows(numbers: Vec<i32>, window_size: usize) -> Vec<DepthVariation> {
+    get_variations(numbers.as_slice().windows(window_size).map(|g| g.iter().sum()).collect())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        Ok(get_numbers_list_from_str(input))
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_variations(numbers.clone())
+            .iter()
+            .filter(|&e| *e == DepthVariation::Increase)
+            .count()
+            .into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer, SolutionError> {
+        Ok(get_variations_windows(numbers.clone(), 3)
+            .iter()
+            .filter(|&e| *e == DepthVariation::Increase)
+            .count()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_variations() {
+        // setup:
+        let input = vec![
+            199,
+            200,
+            208,
+            210,
+            200,
+            207,
+            240,
+            269,
+            260,
+            263,
+        ];
+        let expected = vec![
+            DepthVariation::NA,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Decrease,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Decrease,
+            DepthVariation::Increase,
+        ];
+
+        // execute:
+       let result = get_variations(input.clone());
+       
+       // verify:
+       assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_get_variations_windows() {
+        // setup:
+        let input = vec![
+            199,
+            200,
+            208,
+            210,
+            200,
+            207,
+            240,
+            269,
+            260,
+            263,
+        ];
+        let expected = vec![
+            DepthVariation::NA,
+            DepthVariation::Increase,
+            DepthVariation::Stable,
+            DepthVariation::Decrease,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+            DepthVariation::Increase,
+        ];
+
+        // execute:
+       let result = get_variations_windows(input.clone(), 3);
+       
+       // verify:
+       assert_eq!(expected, result);
+    }
+}
@@ -0,0 +1,263 @@
+use aoc_utils::{Answer, Solution, SolutionError};
+
+pub fn get_numbers_list_from_str(input: &str) -> Vec<usize> {
+    input
+        .replace('\n', "")
+        .chars()
+        .flat_map(|e| e.to_string().parse::<usize>())
+        .collect::<Vec<usize>>()
+}
+
+#[derive(Clone)]
+pub enum MaskType {
+    MostCommonInColumns,
+    LeastCommonInColumns,
+}
+
+pub fn get_rate(input: Vec<usize>, digit_length: usize, mask_type: MaskType) -> usize {
+    usize::from_str_radix(
+        get_mask(input.clone(), digit_length, mask_type)
+            .iter()
+            .map(|&e| {
+                if e {
+                    "1".to_string()
+                } else {
+                    "0".to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("")
+            .as_str(),
+        2,
+    )
+    .unwrap()
+}
+
+pub fn get_gamma_rate(input: Vec<usize>, digit_length: usize) -> usize {
+    get_rate(input, digit_length, MaskType::MostCommonInColumns)
+}
+
+pub fn get_epsilon_rate(input: Vec<usize>, digit_length: usize) -> usize {
+    get_rate(input, digit_length, MaskType::LeastCommonInColumns)
+}
+
+pub fn get_rating(input: &str, digit_length: usize, mask_type: MaskType) -> usize {
+    let mut index = 0;
+    let mut filtered: Vec<&str> = input.lines().collect::<Vec<&str>>();
+
+    while filtered.len() > 1 {
+        let most_common_per_columns = get_mask(
+            filtered
+                .iter()
+                .flat_map(|&e| e.chars().map(|c| if c.to_string() == "1" { 1 } else { 0 }))
+                .collect(),
+            digit_length,
+            mask_type.clone(),
+        );
+
+        filtered.retain(|e| {
+            e.chars().nth(index).unwrap().to_string()
+                == if most_common_per_columns[index] {
+                    "1".to_string()
+                } else {
+                    "0".to_string()
+                }
+        });
+        index += 1;
+    }
+
+    usize::from_str_radix(filtered.first().unwrap(), 2).unwrap()
+}
+
+pub fn get_oxygen_generator_rating(input: &str, digit_length: usize) -> usize {
+    get_rating(input, digit_length, MaskType::MostCommonInColumns)
+}
+
+pub fn get_co2_scrubber_rating(input: &str, digit_length: usize) -> usize {
+    get_rating(input, digit_length, MaskType::LeastCommonInColumns)
+}
+
+pub fn get_mask(input: Vec<usize>, digit_length: usize, mask_type: MaskType) -> Vec<bool> {
+    let mut computed: Vec<i32> = vec![0; digit_length];
+
+    for (i, &b) in input.iter().enumerate() {
+        computed[i % digit_length] += if b == 1 { 1 } else { -1 };
+    }
+
+    computed
+        .iter()
+        .map(|&e| match mask_type {
+            MaskType::MostCommonInColumns => e >= 0,
+            MaskType::LeastCommonInColumns => e < 0,
+        })
+        .collect::<Vec<bool>>()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 3;
+    /// Raw report, and the length of its binary numbers
+    type Input = (String, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+        let digit_length = input
+            .lines()
+            .next()
+            .map(|l| l.trim().len())
+            .ok_or("empty report")?;
+        Ok((input.to_string(), digit_length))
+    }
+
+    fn part1((input, digit_length): &Self::Input) -> Result<Answer, SolutionError> {
+        let numbers = get_numbers_list_from_str(input);
+        Ok((get_gamma_rate(numbers.clone(), *digit_length)
+            * get_epsilon_rate(numbers, *digit_length))
+        .into())
+    }
+
+    fn part2((input, digit_length): &Self::Input) -> Result<Answer, SolutionError> {
+        Ok((get_oxygen_generator_rating(input, *digit_length)
+            * get_co2_scrubber_rating(input, *digit_length))
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_numbers_list_from_str() {
+        // setup:
+        let input = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+        // execute:
+        let result = get_numbers_list_from_str(input);
+
+        // verify:
+        assert_eq!(
+            vec![
+                0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1,
+                1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+                1, 0, 1, 0
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_get_gamma_rate() {
+        // setup:
+        let input = vec![
+            0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1,
+            1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0,
+            1, 0,
+        ];
+
+        // execute:
+        let result = get_gamma_rate(input, 5);
+
+        // verify:
+        assert_eq!(22, result);
+    }
+
+    #[test]
+    fn test_get_epsilon_rate() {
+        // setup:
+        let input = vec![
+            0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 1, 1, 1,
+            1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0,
+            1, 0,
+        ];
+
+        // execute:
+        let result = get_epsilon_rate(input, 5);
+
+        // verify:
+        assert_eq!(9, result);
+    }
+
+    #[test]
+    fn test_get_oxygen_generator_rating() {
+        // setup:
+        let input = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+        // execute:
+        let result = get_oxygen_generator_rating(input, 5);
+
+        // verify:
+        assert_eq!(23, result);
+    }
+
+    #[test]
+    fn test_get_co2_scrubber_rating() {
+        // setup:
+        let input = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+        // execute:
+        let result = get_co2_scrubber_rating(input, 5);
+
+        // verify:
+        assert_eq!(10, result);
+    }
+
+    #[test]
+    fn test_solution_digit_length_from_input() {
+        // setup:
+        let input = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+        // execute:
+        let parsed = Day03::parse(input).unwrap();
+
+        // verify:
+        assert_eq!(5, parsed.1);
+        assert_eq!(Answer::UInt(198), Day03::part1(&parsed).unwrap());
+        assert_eq!(Answer::UInt(230), Day03::part2(&parsed).unwrap());
+    }
+}
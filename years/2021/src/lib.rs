@@ -4,3 +4,24 @@
 pub use aoc_utils::*;
 
 // Day modules
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+/// Add every 2021 solution to the registry
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day01::Day01>()
+        .register::<day02::Day02>()
+        .register::<day03::Day03>();
+}
+
+#[cfg(test)]
+mod example_tests {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod input_tests {
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+}
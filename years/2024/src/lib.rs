@@ -7,3 +7,25 @@
 pub use aoc_utils::*;
 
 // Day modules
+pub mod day01;
+pub mod day02;
+
+/// Add every 2024 solution to the registry
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day01::Day01>()
+        .register::<day02::Day02>();
+    registry
+        .register_generator::<day01::Day01>()
+        .register_generator::<day02::Day02>();
+}
+
+#[cfg(test)]
+mod example_tests {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod input_tests {
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+}